@@ -0,0 +1,117 @@
+//! Compares the indexed timezone search against the previous full scan, which cloned every
+//! name and scored all of them on each keystroke. Run with `cargo bench`.
+
+use chrozone::util::{
+    float::TotalDouble,
+    index::{Match, TIMEZONES},
+    sort::top_n_by_key,
+};
+use core::{
+    cmp::Reverse,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::LazyLock,
+    time::Instant,
+};
+
+/// Counts every allocation made through the system allocator.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Every prefix of these queries is searched as if typed one keystroke at a time.
+const QUERIES: [&str; 6] =
+    ["Asia/Manila", "America/New_York", "Europe/Berlin", "los angeles", "tokyo", "UTC"];
+
+/// Number of results requested by the autocompletion.
+const COUNT: usize = 25;
+
+/// Number of times each query is repeated.
+const ROUNDS: usize = 20;
+
+/// The implementation prior to the index.
+fn scan(query: &str, count: usize) -> Box<[Box<str>]> {
+    static CACHED_TIMEZONES: LazyLock<Box<[Box<str>]>> = LazyLock::new(|| {
+        jiff::tz::db()
+            .available()
+            .map(|tz| tz.as_str().into())
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+
+    let mut names = CACHED_TIMEZONES.clone();
+    let mut cache = hashbrown::HashMap::with_capacity(32);
+
+    top_n_by_key(&mut names, count, |tz| {
+        if !cache.contains_key(tz) {
+            let score = textdistance::str::jaro_winkler(tz, query);
+            let tz = tz.clone();
+            unsafe { cache.insert_unique_unchecked(tz, score) };
+        }
+        Reverse(TotalDouble(cache[tz]))
+    });
+
+    names
+}
+
+fn index(query: &str, count: usize) -> Vec<Match<'static>> {
+    TIMEZONES.search(query, count)
+}
+
+/// Runs the `search` over every keystroke of every query. Prints the mean latency and
+/// the mean number of allocations per keystroke.
+fn bench<T>(name: &str, search: impl Fn(&str, usize) -> T) {
+    let keystrokes: Vec<_> = QUERIES
+        .iter()
+        .flat_map(|query| query.char_indices().map(|(end, c)| &query[..end + c.len_utf8()]))
+        .collect();
+
+    // Warm up the caches (and the lazily built tables)
+    for query in &keystrokes {
+        black_box(search(query, COUNT));
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for query in &keystrokes {
+            black_box(search(black_box(query), COUNT));
+        }
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+
+    let total = (ROUNDS * keystrokes.len()) as u32;
+    println!(
+        "{name:>5}: {:>10.2?}/keystroke {:>8} allocations/keystroke",
+        elapsed / total,
+        allocations / total as usize
+    );
+}
+
+fn main() {
+    bench("scan", scan);
+    bench("index", index);
+}
@@ -0,0 +1,64 @@
+//! Replays the picks logged into `PICK_LOG_PATH` to measure the top-1 accuracy of the
+//! timezone autocompletions with and without the popularity prior. The prior is learned
+//! online: each pick is only learned after its queries have been evaluated.
+//!
+//! ```sh
+//! cargo run --example evaluate LOG [WEIGHT] [HALF_LIFE_DAYS]
+//! ```
+//!
+//! The weight and the half-life default to those of the server.
+
+use chrozone::util::{
+    Search, autocomplete_tz,
+    popularity::{Boosts, Logged, Popularity, Prior},
+};
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let Some(path) = args.next() else {
+        anyhow::bail!("usage: evaluate LOG [WEIGHT] [HALF_LIFE_DAYS]");
+    };
+    let mut prior = Prior::DEFAULT;
+    if let Some(weight) = args.next() {
+        prior.weight = weight.parse()?;
+    }
+    if let Some(days) = args.next() {
+        prior.half_life = jiff::SignedDuration::try_from_secs_f64(days.parse::<f64>()? * 86400.0)?;
+    }
+
+    let log = std::fs::read_to_string(path)?;
+    let mut picks: Vec<Logged> = log
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    picks.sort_by_key(|pick| pick.at);
+
+    let mut popularity = Popularity::default();
+    let (mut queries, mut baseline, mut boosted) = (0, 0, 0);
+    for Logged { at, queries: typed, zone } in &picks {
+        for query in typed {
+            let top = |boosts: &Boosts| {
+                let found =
+                    autocomplete_tz(query, Search { boosts: Some(boosts), ..Search::new(1) });
+                found.first().is_some_and(|found| *found.zone == **zone)
+            };
+            queries += 1;
+            baseline += usize::from(top(&Default::default()));
+            boosted += usize::from(top(&popularity.boosts(query, *at, &prior)));
+        }
+        popularity.record(typed.iter().map(|query| &**query), zone, *at, &prior);
+    }
+
+    let percent =
+        |hits: usize| if queries == 0 { 0.0 } else { 100.0 * hits as f64 / queries as f64 };
+    println!(
+        "{} picks, {queries} queries (weight {}, half-life {:#})",
+        picks.len(),
+        prior.weight,
+        prior.half_life
+    );
+    println!("   without prior: {:6.2}% top-1 ({baseline} hits)", percent(baseline));
+    println!("      with prior: {:6.2}% top-1 ({boosted} hits)", percent(boosted));
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! Generates the dataset of timezone aliases (i.e., countries, country codes, and cities)
+//! embedded into the search index. The countries are derived from tzdata's `zone.tab`,
+//! `zone1970.tab`, and `iso3166.tab` whereas the cities are from `cities.tsv` alongside
+//! this generator.
+//!
+//! ```sh
+//! cargo run --example zonedata [TZDIR]
+//! ```
+//!
+//! The tzdata directory defaults to `/usr/share/zoneinfo`.
+
+use std::{collections::BTreeMap, fmt::Write as _, path::Path};
+
+const OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/util/zones.tsv");
+const CITIES: &str = include_str!("cities.tsv");
+
+fn main() -> anyhow::Result<()> {
+    let dir = std::env::args().nth(1).unwrap_or_else(|| String::from("/usr/share/zoneinfo"));
+    let dir = Path::new(&dir);
+
+    // Country codes to their names
+    let iso3166 = std::fs::read_to_string(dir.join("iso3166.tab"))?;
+    let countries: BTreeMap<_, _> =
+        rows(&iso3166).map(|columns| (columns[0], columns[1])).collect();
+
+    // Country codes to their zones in order of appearance (i.e., most populous first)
+    let mut zones = BTreeMap::<_, Vec<_>>::new();
+    for file in ["zone.tab", "zone1970.tab"] {
+        let table = std::fs::read_to_string(dir.join(file))?;
+        for columns in rows(&table) {
+            let &[codes, _, zone, ..] = columns.as_slice() else {
+                anyhow::bail!("malformed row in {file}: {columns:?}");
+            };
+            for code in codes.split(',') {
+                let zones = zones.entry(String::from(code)).or_default();
+                if !zones.iter().any(|other| other == zone) {
+                    zones.push(String::from(zone));
+                }
+            }
+        }
+    }
+
+    let mut output = String::from(
+        "# Generated by `cargo run --example zonedata`. Do not edit by hand.\n\
+         # Each line maps an alias to its zones (in order of relevance), separated by a tab.\n",
+    );
+    for (code, zones) in &zones {
+        let Some(country) = countries.get(code.as_str()) else {
+            anyhow::bail!("unknown country code {code}");
+        };
+        let zones = zones.join(" ");
+        writeln!(output, "{country}\t{zones}")?;
+        writeln!(output, "{code}\t{zones}")?;
+    }
+
+    for columns in rows(CITIES) {
+        let &[city, zone] = columns.as_slice() else {
+            anyhow::bail!("malformed city: {columns:?}");
+        };
+        if jiff::tz::TimeZone::get(zone).is_err() {
+            anyhow::bail!("unknown zone {zone} for {city}");
+        }
+        writeln!(output, "{city}\t{zone}")?;
+    }
+
+    std::fs::write(OUTPUT, output)?;
+    Ok(())
+}
+
+/// Iterates over the tab-separated columns of every row in the `table` except comments.
+fn rows(table: &str) -> impl Iterator<Item = Vec<&str>> {
+    table
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split('\t').collect())
+}
@@ -0,0 +1,199 @@
+use super::{Calendar, Catalog, Commands, Epoch, Errors, Help, Info, Input, Names, Regions};
+
+pub static EN: Catalog = Catalog {
+    locales: &["en-US", "en-GB"],
+    calendar: Calendar {
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        weekdays: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+        date: "{weekday}, {month} {day}, {year}",
+        day_month: "{month} {day}",
+    },
+    commands: Commands {
+        help: "Summon the help menu.",
+        help_command: "Ask for more details for a specific command.",
+        info: "Get important links and information about the app.",
+        epoch: "Get the ISO-8601 timestamp from a date and timezone.",
+        epoch_timezone: "The timezone to base the date from. Autocompletions enabled.",
+        epoch_year: "Sets the year. Omit all date options to enter the date in a form instead.",
+        epoch_month: "Sets the month (defaults to January).",
+        epoch_day: "Sets the day (defaults to the first day).",
+        epoch_hour: "Sets the hour in military time (defaults to the 0th hour).",
+        epoch_minute: "Sets the minute (defaults to 0).",
+        epoch_second: "Sets the second (defaults to 0).",
+        epoch_preview: "Enables preview mode for all timestamp formatting options. Enabled by default.",
+        countdown: "Post a message which counts down to some date and time.",
+        countdown_to: "The target date and time in YYYY-MM-DD HH:MM format.",
+        countdown_timezone: "The timezone of the target date and time.",
+        countdown_title: "What the countdown is for.",
+        birthday: "Manage birthday announcements in this channel.",
+        birthday_set: "Announce your birthday in this channel when the day begins in your timezone.",
+        birthday_date: "Your birthday in MM-DD format (e.g., 02-29).",
+        birthday_timezone: "Your timezone (defaults to UTC).",
+        birthday_fallback: "When to celebrate February 29 birthdays in common years.",
+        birthday_unset: "Stop announcing your birthday in this channel.",
+        dst_alerts: "Manage daylight saving time alerts for this channel.",
+        dst_subscribe: "Post a warning in this channel before each transition of a timezone.",
+        dst_subscribe_zone: "The timezone to watch.",
+        dst_lead: "How many days in advance to post the warning.",
+        dst_unsubscribe: "Stop posting warnings for a timezone in this channel.",
+        dst_unsubscribe_zone: "The timezone to stop watching.",
+        abbr: "Look up timezone abbreviations.",
+        abbr_lookup: "List the zones which use (or used) an abbreviation.",
+        abbr_lookup_abbreviation: "The abbreviation to look up (e.g., PST).",
+        abbr_zone: "List the abbreviations which a timezone uses (or used).",
+        abbr_zone_zone: "The timezone whose abbreviations to list.",
+        zones: "Manage the timezones suggested to you first.",
+        zones_favorite: "Pin a timezone so that it is always suggested first.",
+        zones_favorite_zone: "The timezone to pin.",
+        zones_unfavorite: "Unpin one of your favorite timezones.",
+        zones_unfavorite_zone: "The timezone to unpin.",
+        zones_history: "Turn the suggestions of your recently used timezones on or off.",
+        zones_history_enabled: "Whether to record your recently used timezones.",
+    },
+    names: Names {
+        epoch: "epoch",
+        help: "help",
+        info: "info",
+        countdown: "countdown",
+        birthday: "birthday",
+        dst_alerts: "dst-alerts",
+        abbr: "abbr",
+        zones: "zones",
+        timezone: "timezone",
+        year: "year",
+        month: "month",
+        day: "day",
+        hour: "hour",
+        minute: "minute",
+        second: "second",
+        preview: "preview",
+        command: "command",
+        to: "to",
+        title: "title",
+        set: "set",
+        date: "date",
+        fallback: "fallback",
+        unset: "unset",
+        subscribe: "subscribe",
+        zone: "zone",
+        lead: "lead",
+        unsubscribe: "unsubscribe",
+        lookup: "lookup",
+        abbreviation: "abbreviation",
+        favorite: "favorite",
+        unfavorite: "unfavorite",
+        history: "history",
+        enabled: "enabled",
+    },
+    errors: Errors {
+        ambiguous_timezone: "**{abbreviation}** stands for different offsets in different places. Please enter one of these timezones (or a UTC offset) instead:\n{candidates}",
+        cannot_post_publicly: "I am not allowed to post publicly here. Please copy the timestamp manually.",
+        fatal: "Unrecoverable error. This is unexpected behavior. Please file a bug report.",
+        invalid_args: "Invalid command arguments.",
+        invalid_component: "This message is no longer interactive. Please invoke the command again.",
+        invalid_date: "Invalid date. Please use the `MM-DD` format (e.g., `02-29`).",
+        invalid_date_time: "Invalid date and time. Please use the `YYYY-MM-DD HH:MM` format (e.g., `2030-01-01 09:30`).",
+        invalid_field: "Invalid value in the **{field}** field. {hint}",
+        invalid_option: "Invalid value for the `{option}` option.",
+        in_past: "The given date and time has already passed.",
+        missing_channel: "This command must be invoked from within a channel.",
+        missing_payload: "No interaction data present.",
+        missing_required: "Required arguments not provided.",
+        missing_option: "The `{option}` option is required.",
+        unknown_command: "Unknown command name.",
+        unregistered_command: "This command is outdated. Please reload Discord to refresh the command list and try again.",
+        unknown_timezone: "Unknown timezone. Please enter an IANA name (e.g., `Asia/Manila`), a UTC offset (e.g., `UTC+08:00`), or an abbreviation (e.g., `CEST`).",
+        unsupported_interaction_type: "Unsupported interaction type.",
+        out_of_range: "A value is out of range. It is either too large or too small.",
+        option_out_of_range: "The `{option}` option must be between {min} and {max}.",
+        storage: "Failed to save your changes. Please try again later.",
+    },
+    epoch: Epoch {
+        title: "Timestamp Preview",
+        intro: "Here are the possible ways to format your timestamp.",
+        showing: "Showing {date} at `{time}` in **{zone}** ({offset}).",
+        styles: [
+            "Short Time",
+            "Long Time",
+            "Short Date",
+            "Long Date",
+            "Short Full Date + Time",
+            "Long Full Date + Time",
+            "Relative",
+        ],
+        shifts: ["−1 day", "−1 hour", "+1 hour", "+1 day"],
+        style_placeholder: "Switch the timestamp style",
+        zone_placeholder: "Keep the date and time, but in another timezone",
+        share: "📢 Post publicly",
+        shared: "{timestamp} (shared by {user})",
+        date: Input {
+            label: "Date",
+            description: "Must be in the YYYY-MM-DD format.",
+            hint: "Please use the `YYYY-MM-DD` format (e.g., `2030-01-31`).",
+        },
+        time: Input {
+            label: "Time",
+            description: "Must be in the 24-hour HH:MM or HH:MM:SS format. Defaults to midnight.",
+            hint: "Please use the 24-hour `HH:MM` or `HH:MM:SS` format (e.g., `13:30`).",
+        },
+        timezone: Input {
+            label: "Timezone",
+            description: "An IANA name, a UTC offset, or an abbreviation.",
+            hint: "Please provide an IANA name (e.g., `Asia/Manila`), a UTC offset (e.g., `UTC+08:00`), or an abbreviation (e.g., `CEST`).",
+        },
+    },
+    help: Help {
+        menu_title: "Chrozone Help",
+        menu_description: "List of supported commands and their arguments.",
+        menu_help: "Summon this help menu.",
+        menu_epoch: "Get the ISO-8601 timestamp (in seconds) for some date and timezone.",
+        epoch_title: "`/epoch` Command",
+        epoch_description: "Generates the ISO-8601 timestamp at a given date and timezone.",
+        epoch_timezone: "Required when any date option is given. Must be an officially registered timezone from the IANA Time Zone Database. For convenience, dynamic autocompletions are enabled.",
+        epoch_year: "Required when any other date option is given. Must be a reasonably valid year. If all date options are omitted, a form for entering the date and time as text is shown instead.",
+        epoch_month: "Must be a value from `1` (default) to `12`, where `1` is {first} and `12` is {last}.",
+        epoch_day: "Must be a value from `1` (default) to `31`. Note that the days `29` to `31` are only invalid for certain months.",
+        epoch_hour: "Must be a value from `0` (default) to `23` (i.e. 24-hour format), where `0` is `12am` and `23` is `11pm`",
+        epoch_minute: "Must be a value from `0` (default) to `59`.",
+        epoch_second: "Must be a value from `0` (default) to `60`. The 60th second accounts for possible leap seconds.",
+        epoch_preview: "Enables preview mode for all timestamp formatting options. Defaults to `true`.",
+        help_title: "`/help` Command",
+        help_description: "Provides extra details for specific commands.",
+        help_epoch: "Shows extra information for each argument of the `/epoch` command.",
+        help_help: "Provides extra details on how to use the `/help` command.",
+        info_title: "Chrozone Information",
+        info_description: "Show important information about the app: invite link, bug reports, and source code.",
+        info_info: "Show the information page.",
+    },
+    info: Info {
+        description: "Chrozone is an [open-source](https://github.com/BastiDood/chrozone) bot written in [Rust](https://www.rust-lang.org/) by [`@BastiDood`](https://github.com/BastiDood) for time zone utilities and timestamp formatting.",
+        install: "Install App",
+        report: "Report a Bug",
+        fork: "Fork the Code",
+    },
+    regions: Regions {
+        africa: "Africa",
+        america: "America",
+        antarctica: "Antarctica",
+        arctic: "Arctic",
+        asia: "Asia",
+        atlantic: "Atlantic",
+        australia: "Australia",
+        europe: "Europe",
+        indian: "Indian",
+        pacific: "Pacific",
+    },
+};
@@ -0,0 +1,199 @@
+use super::{Calendar, Catalog, Commands, Epoch, Errors, Help, Info, Input, Names, Regions};
+
+pub static ES: Catalog = Catalog {
+    locales: &["es-ES", "es-419"],
+    calendar: Calendar {
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+        date: "{weekday}, {day} de {month} de {year}",
+        day_month: "{day} de {month}",
+    },
+    commands: Commands {
+        help: "Abre el menú de ayuda.",
+        help_command: "Pide más detalles sobre un comando específico.",
+        info: "Obtén enlaces e información importante sobre la aplicación.",
+        epoch: "Obtén la marca de tiempo ISO-8601 de una fecha y zona horaria.",
+        epoch_timezone: "La zona horaria de la fecha. Con sugerencias automáticas.",
+        epoch_year: "Establece el año. Omite todas las opciones de fecha para escribirla en un formulario.",
+        epoch_month: "Establece el mes (por defecto, enero).",
+        epoch_day: "Establece el día (por defecto, el primero).",
+        epoch_hour: "Establece la hora en formato de 24 horas (por defecto, la hora 0).",
+        epoch_minute: "Establece el minuto (por defecto, 0).",
+        epoch_second: "Establece el segundo (por defecto, 0).",
+        epoch_preview: "Activa la vista previa de todos los formatos de marca de tiempo. Activada por defecto.",
+        countdown: "Publica un mensaje con una cuenta regresiva hasta una fecha y hora.",
+        countdown_to: "La fecha y hora objetivo en formato YYYY-MM-DD HH:MM.",
+        countdown_timezone: "La zona horaria de la fecha y hora objetivo.",
+        countdown_title: "El motivo de la cuenta regresiva.",
+        birthday: "Administra los anuncios de cumpleaños en este canal.",
+        birthday_set: "Anuncia tu cumpleaños en este canal cuando empiece el día en tu zona horaria.",
+        birthday_date: "Tu cumpleaños en formato MM-DD (p. ej., 02-29).",
+        birthday_timezone: "Tu zona horaria (por defecto, UTC).",
+        birthday_fallback: "Cuándo celebrar los cumpleaños del 29 de febrero en años no bisiestos.",
+        birthday_unset: "Deja de anunciar tu cumpleaños en este canal.",
+        dst_alerts: "Administra las alertas de horario de verano de este canal.",
+        dst_subscribe: "Publica un aviso en este canal antes de cada cambio de hora de una zona horaria.",
+        dst_subscribe_zone: "La zona horaria a vigilar.",
+        dst_lead: "Con cuántos días de anticipación publicar el aviso.",
+        dst_unsubscribe: "Deja de publicar avisos de una zona horaria en este canal.",
+        dst_unsubscribe_zone: "La zona horaria que se dejará de vigilar.",
+        abbr: "Consulta abreviaturas de zonas horarias.",
+        abbr_lookup: "Lista las zonas que usan (o usaron) una abreviatura.",
+        abbr_lookup_abbreviation: "La abreviatura a consultar (p. ej., PST).",
+        abbr_zone: "Lista las abreviaturas que usa (o usó) una zona horaria.",
+        abbr_zone_zone: "La zona horaria cuyas abreviaturas se listarán.",
+        zones: "Administra las zonas horarias que se te sugieren primero.",
+        zones_favorite: "Fija una zona horaria para que siempre se sugiera primero.",
+        zones_favorite_zone: "La zona horaria a fijar.",
+        zones_unfavorite: "Desfija una de tus zonas horarias favoritas.",
+        zones_unfavorite_zone: "La zona horaria a desfijar.",
+        zones_history: "Activa o desactiva las sugerencias de tus zonas horarias recientes.",
+        zones_history_enabled: "Si se registran tus zonas horarias usadas recientemente.",
+    },
+    names: Names {
+        epoch: "época",
+        help: "ayuda",
+        info: "info",
+        countdown: "cuenta-regresiva",
+        birthday: "cumpleaños",
+        dst_alerts: "alertas-horario-verano",
+        abbr: "abrev",
+        zones: "zonas",
+        timezone: "zona-horaria",
+        year: "año",
+        month: "mes",
+        day: "día",
+        hour: "hora",
+        minute: "minuto",
+        second: "segundo",
+        preview: "vista-previa",
+        command: "comando",
+        to: "hasta",
+        title: "título",
+        set: "fijar",
+        date: "fecha",
+        fallback: "alternativa",
+        unset: "quitar",
+        subscribe: "suscribir",
+        zone: "zona",
+        lead: "antelación",
+        unsubscribe: "desuscribir",
+        lookup: "buscar",
+        abbreviation: "abreviatura",
+        favorite: "favorita",
+        unfavorite: "quitar-favorita",
+        history: "historial",
+        enabled: "activado",
+    },
+    errors: Errors {
+        ambiguous_timezone: "**{abbreviation}** corresponde a distintos desfases en distintos lugares. Por favor, indica una de estas zonas horarias (o un desfase UTC) en su lugar:\n{candidates}",
+        cannot_post_publicly: "No tengo permiso para publicar aquí. Por favor, copia la marca de tiempo manualmente.",
+        fatal: "Error irrecuperable. Este comportamiento es inesperado. Por favor, reporta el error.",
+        invalid_args: "Argumentos del comando no válidos.",
+        invalid_component: "Este mensaje ya no es interactivo. Por favor, vuelve a usar el comando.",
+        invalid_date: "Fecha no válida. Por favor, usa el formato `MM-DD` (p. ej., `02-29`).",
+        invalid_date_time: "Fecha y hora no válidas. Por favor, usa el formato `YYYY-MM-DD HH:MM` (p. ej., `2030-01-01 09:30`).",
+        invalid_field: "Valor no válido en el campo **{field}**. {hint}",
+        invalid_option: "Valor no válido para la opción `{option}`.",
+        in_past: "La fecha y hora indicadas ya pasaron.",
+        missing_channel: "Este comando debe usarse dentro de un canal.",
+        missing_payload: "No hay datos de interacción.",
+        missing_required: "Faltan argumentos obligatorios.",
+        missing_option: "La opción `{option}` es obligatoria.",
+        unknown_command: "Nombre de comando desconocido.",
+        unregistered_command: "Este comando está desactualizado. Por favor, recarga Discord para actualizar la lista de comandos e inténtalo de nuevo.",
+        unknown_timezone: "Zona horaria desconocida. Indica un nombre de la IANA (p. ej., `America/Mexico_City`), un desfase UTC (p. ej., `UTC-06:00`) o una abreviatura (p. ej., `CEST`).",
+        unsupported_interaction_type: "Tipo de interacción no compatible.",
+        out_of_range: "Un valor está fuera de rango. Es demasiado grande o demasiado pequeño.",
+        option_out_of_range: "La opción `{option}` debe estar entre {min} y {max}.",
+        storage: "No se pudieron guardar tus cambios. Por favor, inténtalo más tarde.",
+    },
+    epoch: Epoch {
+        title: "Vista previa de la marca de tiempo",
+        intro: "Estas son las posibles maneras de formatear tu marca de tiempo.",
+        showing: "Mostrando {date} a las `{time}` en **{zone}** ({offset}).",
+        styles: [
+            "Hora corta",
+            "Hora larga",
+            "Fecha corta",
+            "Fecha larga",
+            "Fecha y hora cortas",
+            "Fecha y hora largas",
+            "Relativa",
+        ],
+        shifts: ["−1 día", "−1 hora", "+1 hora", "+1 día"],
+        style_placeholder: "Cambiar el estilo de la marca de tiempo",
+        zone_placeholder: "Mantener la fecha y hora, pero en otra zona horaria",
+        share: "📢 Publicar para todos",
+        shared: "{timestamp} (compartido por {user})",
+        date: Input {
+            label: "Fecha",
+            description: "Debe estar en el formato YYYY-MM-DD.",
+            hint: "Por favor, usa el formato `YYYY-MM-DD` (p. ej., `2030-01-31`).",
+        },
+        time: Input {
+            label: "Hora",
+            description: "Debe estar en el formato de 24 horas HH:MM o HH:MM:SS. Por defecto, medianoche.",
+            hint: "Por favor, usa el formato de 24 horas `HH:MM` o `HH:MM:SS` (p. ej., `13:30`).",
+        },
+        timezone: Input {
+            label: "Zona horaria",
+            description: "Un nombre de la IANA, un desfase UTC o una abreviatura.",
+            hint: "Por favor, indica un nombre de la IANA (p. ej., `America/Mexico_City`), un desfase UTC (p. ej., `UTC-06:00`) o una abreviatura (p. ej., `CEST`).",
+        },
+    },
+    help: Help {
+        menu_title: "Ayuda de Chrozone",
+        menu_description: "Lista de comandos disponibles y sus argumentos.",
+        menu_help: "Muestra este menú de ayuda.",
+        menu_epoch: "Obtén la marca de tiempo ISO-8601 (en segundos) de una fecha y zona horaria.",
+        epoch_title: "Comando `/epoch`",
+        epoch_description: "Genera la marca de tiempo ISO-8601 de una fecha y zona horaria dadas.",
+        epoch_timezone: "Obligatoria si se indica alguna opción de fecha. Debe ser una zona horaria registrada oficialmente en la base de datos de zonas horarias de la IANA. Por comodidad, se ofrecen sugerencias automáticas.",
+        epoch_year: "Obligatorio si se indica alguna otra opción de fecha. Debe ser un año razonablemente válido. Si se omiten todas las opciones de fecha, se muestra un formulario para escribir la fecha y hora como texto.",
+        epoch_month: "Debe ser un valor de `1` (por defecto) a `12`, donde `1` es {first} y `12` es {last}.",
+        epoch_day: "Debe ser un valor de `1` (por defecto) a `31`. Ten en cuenta que los días `29` a `31` no son válidos en ciertos meses.",
+        epoch_hour: "Debe ser un valor de `0` (por defecto) a `23` (es decir, formato de 24 horas), donde `0` es `12am` y `23` es `11pm`",
+        epoch_minute: "Debe ser un valor de `0` (por defecto) a `59`.",
+        epoch_second: "Debe ser un valor de `0` (por defecto) a `60`. El segundo 60 contempla posibles segundos intercalares.",
+        epoch_preview: "Activa la vista previa de todos los formatos de marca de tiempo. Por defecto, `true`.",
+        help_title: "Comando `/help`",
+        help_description: "Ofrece más detalles sobre comandos específicos.",
+        help_epoch: "Muestra más información sobre cada argumento del comando `/epoch`.",
+        help_help: "Ofrece más detalles sobre cómo usar el comando `/help`.",
+        info_title: "Información de Chrozone",
+        info_description: "Muestra información importante sobre la aplicación: enlace de invitación, reporte de errores y código fuente.",
+        info_info: "Muestra la página de información.",
+    },
+    info: Info {
+        description: "Chrozone es un bot de [código abierto](https://github.com/BastiDood/chrozone) escrito en [Rust](https://www.rust-lang.org/) por [`@BastiDood`](https://github.com/BastiDood) con utilidades de zonas horarias y formato de marcas de tiempo.",
+        install: "Instalar la app",
+        report: "Reportar un error",
+        fork: "Bifurcar el código",
+    },
+    regions: Regions {
+        africa: "África",
+        america: "América",
+        antarctica: "Antártida",
+        arctic: "Ártico",
+        asia: "Asia",
+        atlantic: "Atlántico",
+        australia: "Australia",
+        europe: "Europa",
+        indian: "Índico",
+        pacific: "Pacífico",
+    },
+};
@@ -0,0 +1,199 @@
+use super::{Calendar, Catalog, Commands, Epoch, Errors, Help, Info, Input, Names, Regions};
+
+pub static FIL: Catalog = Catalog {
+    locales: &[],
+    calendar: Calendar {
+        months: [
+            "Enero",
+            "Pebrero",
+            "Marso",
+            "Abril",
+            "Mayo",
+            "Hunyo",
+            "Hulyo",
+            "Agosto",
+            "Setyembre",
+            "Oktubre",
+            "Nobyembre",
+            "Disyembre",
+        ],
+        weekdays: ["Lunes", "Martes", "Miyerkules", "Huwebes", "Biyernes", "Sabado", "Linggo"],
+        date: "{weekday}, {month} {day}, {year}",
+        day_month: "{month} {day}",
+    },
+    commands: Commands {
+        help: "Ipakita ang help menu.",
+        help_command: "Humingi ng karagdagang detalye tungkol sa isang command.",
+        info: "Kunin ang mahahalagang link at impormasyon tungkol sa app.",
+        epoch: "Kunin ang ISO-8601 timestamp mula sa isang petsa at timezone.",
+        epoch_timezone: "Ang timezone ng petsa. May mga autocompletion.",
+        epoch_year: "Itakda ang taon. Huwag magbigay ng opsyon ng petsa para i-type ito sa isang form.",
+        epoch_month: "Itakda ang buwan (Enero kung wala).",
+        epoch_day: "Itakda ang araw (unang araw kung wala).",
+        epoch_hour: "Itakda ang oras sa 24-oras na format (ika-0 oras kung wala).",
+        epoch_minute: "Itakda ang minuto (0 kung wala).",
+        epoch_second: "Itakda ang segundo (0 kung wala).",
+        epoch_preview: "Ipakita ang preview ng lahat ng format ng timestamp. Naka-enable kung wala.",
+        countdown: "Mag-post ng mensaheng nagbibilang pababa hanggang sa isang petsa at oras.",
+        countdown_to: "Ang target na petsa at oras sa format na YYYY-MM-DD HH:MM.",
+        countdown_timezone: "Ang timezone ng target na petsa at oras.",
+        countdown_title: "Kung para saan ang countdown.",
+        birthday: "Pamahalaan ang mga anunsyo ng kaarawan sa channel na ito.",
+        birthday_set: "Ianunsyo ang iyong kaarawan sa channel na ito pagsapit ng araw sa iyong timezone.",
+        birthday_date: "Ang iyong kaarawan sa format na MM-DD (hal., 02-29).",
+        birthday_timezone: "Ang iyong timezone (UTC kung wala).",
+        birthday_fallback: "Kailan ipagdiriwang ang mga kaarawan sa Pebrero 29 sa mga karaniwang taon.",
+        birthday_unset: "Itigil ang pag-anunsyo ng iyong kaarawan sa channel na ito.",
+        dst_alerts: "Pamahalaan ang mga alerto sa daylight saving time para sa channel na ito.",
+        dst_subscribe: "Mag-post ng babala sa channel na ito bago ang bawat pagbabago ng oras ng isang timezone.",
+        dst_subscribe_zone: "Ang timezone na babantayan.",
+        dst_lead: "Ilang araw bago mag-post ng babala.",
+        dst_unsubscribe: "Itigil ang pag-post ng mga babala para sa isang timezone sa channel na ito.",
+        dst_unsubscribe_zone: "Ang timezone na hindi na babantayan.",
+        abbr: "Hanapin ang mga abbreviation ng timezone.",
+        abbr_lookup: "Ilista ang mga zone na gumagamit (o gumamit) ng isang abbreviation.",
+        abbr_lookup_abbreviation: "Ang abbreviation na hahanapin (hal., PST).",
+        abbr_zone: "Ilista ang mga abbreviation na ginagamit (o ginamit) ng isang timezone.",
+        abbr_zone_zone: "Ang timezone na ililista ang mga abbreviation.",
+        zones: "Pamahalaan ang mga timezone na unang iminumungkahi sa iyo.",
+        zones_favorite: "I-pin ang isang timezone para palagi itong unang imungkahi.",
+        zones_favorite_zone: "Ang timezone na ipi-pin.",
+        zones_unfavorite: "Alisin sa pin ang isa sa mga paborito mong timezone.",
+        zones_unfavorite_zone: "Ang timezone na aalisin sa pin.",
+        zones_history: "I-on o i-off ang mungkahi ng mga timezone na ginamit mo kamakailan.",
+        zones_history_enabled: "Kung itatala ang mga timezone na ginamit mo kamakailan.",
+    },
+    names: Names {
+        epoch: "epoch",
+        help: "tulong",
+        info: "impormasyon",
+        countdown: "countdown",
+        birthday: "kaarawan",
+        dst_alerts: "dst-alerts",
+        abbr: "daglat",
+        zones: "mga-zone",
+        timezone: "timezone",
+        year: "taon",
+        month: "buwan",
+        day: "araw",
+        hour: "oras",
+        minute: "minuto",
+        second: "segundo",
+        preview: "silipin",
+        command: "utos",
+        to: "hanggang",
+        title: "pamagat",
+        set: "itakda",
+        date: "petsa",
+        fallback: "kahalili",
+        unset: "alisin",
+        subscribe: "mag-subscribe",
+        zone: "zone",
+        lead: "agwat",
+        unsubscribe: "mag-unsubscribe",
+        lookup: "hanapin",
+        abbreviation: "daglat",
+        favorite: "paborito",
+        unfavorite: "alisin-paborito",
+        history: "kasaysayan",
+        enabled: "naka-on",
+    },
+    errors: Errors {
+        ambiguous_timezone: "Iba-iba ang offset ng **{abbreviation}** sa iba't ibang lugar. Pakilagay na lang ang isa sa mga timezone na ito (o isang UTC offset):\n{candidates}",
+        cannot_post_publicly: "Hindi ako pinapayagang mag-post dito nang publiko. Pakikopya na lang nang mano-mano ang timestamp.",
+        fatal: "Hindi na maaayos na error. Hindi ito inaasahan. Paki-report ang bug.",
+        invalid_args: "Hindi wasto ang mga argumento ng command.",
+        invalid_component: "Hindi na interactive ang mensaheng ito. Pakigamit muli ang command.",
+        invalid_date: "Hindi wastong petsa. Pakigamit ang format na `MM-DD` (hal., `02-29`).",
+        invalid_date_time: "Hindi wastong petsa at oras. Pakigamit ang format na `YYYY-MM-DD HH:MM` (hal., `2030-01-01 09:30`).",
+        invalid_field: "Hindi wasto ang value sa field na **{field}**. {hint}",
+        invalid_option: "Hindi wastong value para sa opsyong `{option}`.",
+        in_past: "Lumipas na ang ibinigay na petsa at oras.",
+        missing_channel: "Dapat gamitin ang command na ito sa loob ng isang channel.",
+        missing_payload: "Walang interaction data.",
+        missing_required: "Kulang ang mga kinakailangang argumento.",
+        missing_option: "Kinakailangan ang opsyong `{option}`.",
+        unknown_command: "Hindi kilalang pangalan ng command.",
+        unregistered_command: "Luma na ang command na ito. Paki-reload ang Discord para ma-refresh ang listahan ng mga command at subukang muli.",
+        unknown_timezone: "Hindi kilalang timezone. Pakilagay ang pangalan mula sa IANA (hal., `Asia/Manila`), isang UTC offset (hal., `UTC+08:00`), o isang abbreviation (hal., `CEST`).",
+        unsupported_interaction_type: "Hindi suportadong uri ng interaction.",
+        out_of_range: "May value na lampas sa saklaw. Masyado itong malaki o maliit.",
+        option_out_of_range: "Ang opsyong `{option}` ay dapat nasa pagitan ng {min} at {max}.",
+        storage: "Hindi na-save ang iyong mga pagbabago. Pakisubukang muli mamaya.",
+    },
+    epoch: Epoch {
+        title: "Preview ng Timestamp",
+        intro: "Narito ang mga posibleng paraan ng pag-format ng iyong timestamp.",
+        showing: "Ipinapakita ang {date} nang `{time}` sa **{zone}** ({offset}).",
+        styles: [
+            "Maikling Oras",
+            "Mahabang Oras",
+            "Maikling Petsa",
+            "Mahabang Petsa",
+            "Maikling Petsa + Oras",
+            "Mahabang Petsa + Oras",
+            "Relatibo",
+        ],
+        shifts: ["−1 araw", "−1 oras", "+1 oras", "+1 araw"],
+        style_placeholder: "Palitan ang estilo ng timestamp",
+        zone_placeholder: "Panatilihin ang petsa at oras, pero sa ibang timezone",
+        share: "📢 I-post sa publiko",
+        shared: "{timestamp} (ibinahagi ni {user})",
+        date: Input {
+            label: "Petsa",
+            description: "Dapat nasa format na YYYY-MM-DD.",
+            hint: "Pakigamit ang format na `YYYY-MM-DD` (hal., `2030-01-31`).",
+        },
+        time: Input {
+            label: "Oras",
+            description: "Dapat nasa 24-oras na format na HH:MM o HH:MM:SS. Hatinggabi kung wala.",
+            hint: "Pakigamit ang 24-oras na format na `HH:MM` o `HH:MM:SS` (hal., `13:30`).",
+        },
+        timezone: Input {
+            label: "Timezone",
+            description: "Pangalan mula sa IANA, UTC offset, o abbreviation.",
+            hint: "Pakibigay ang pangalan mula sa IANA (hal., `Asia/Manila`), isang UTC offset (hal., `UTC+08:00`), o isang abbreviation (hal., `CEST`).",
+        },
+    },
+    help: Help {
+        menu_title: "Tulong sa Chrozone",
+        menu_description: "Listahan ng mga suportadong command at ang kanilang mga argumento.",
+        menu_help: "Ipakita ang help menu na ito.",
+        menu_epoch: "Kunin ang ISO-8601 timestamp (sa segundo) ng isang petsa at timezone.",
+        epoch_title: "Ang `/epoch` Command",
+        epoch_description: "Gumagawa ng ISO-8601 timestamp para sa ibinigay na petsa at timezone.",
+        epoch_timezone: "Kinakailangan kapag may ibinigay na opsyon ng petsa. Dapat ay opisyal na nakarehistrong timezone mula sa IANA Time Zone Database. May mga autocompletion para mas madali.",
+        epoch_year: "Kinakailangan kapag may ibinigay na ibang opsyon ng petsa. Dapat ay makatwirang taon. Kung walang ibinigay na opsyon ng petsa, may lalabas na form para i-type ang petsa at oras.",
+        epoch_month: "Dapat ay value mula `1` (default) hanggang `12`, kung saan ang `1` ay {first} at ang `12` ay {last}.",
+        epoch_day: "Dapat ay value mula `1` (default) hanggang `31`. Tandaan na hindi wasto ang mga araw na `29` hanggang `31` sa ilang buwan.",
+        epoch_hour: "Dapat ay value mula `0` (default) hanggang `23` (24-oras na format), kung saan ang `0` ay `12am` at ang `23` ay `11pm`",
+        epoch_minute: "Dapat ay value mula `0` (default) hanggang `59`.",
+        epoch_second: "Dapat ay value mula `0` (default) hanggang `60`. Ang ika-60 segundo ay para sa mga posibleng leap second.",
+        epoch_preview: "Ipinapakita ang preview ng lahat ng format ng timestamp. `true` kung wala.",
+        help_title: "Ang `/help` Command",
+        help_description: "Nagbibigay ng karagdagang detalye tungkol sa mga partikular na command.",
+        help_epoch: "Ipinapakita ang karagdagang impormasyon sa bawat argumento ng `/epoch` command.",
+        help_help: "Nagbibigay ng karagdagang detalye kung paano gamitin ang `/help` command.",
+        info_title: "Impormasyon tungkol sa Chrozone",
+        info_description: "Ipakita ang mahalagang impormasyon tungkol sa app: invite link, pag-report ng bug, at source code.",
+        info_info: "Ipakita ang pahina ng impormasyon.",
+    },
+    info: Info {
+        description: "Ang Chrozone ay isang [open-source](https://github.com/BastiDood/chrozone) na bot na isinulat sa [Rust](https://www.rust-lang.org/) ni [`@BastiDood`](https://github.com/BastiDood) para sa mga timezone utility at pag-format ng timestamp.",
+        install: "I-install ang App",
+        report: "Mag-report ng Bug",
+        fork: "I-fork ang Code",
+    },
+    regions: Regions {
+        africa: "Aprika",
+        america: "Amerika",
+        antarctica: "Antarktika",
+        arctic: "Artiko",
+        asia: "Asya",
+        atlantic: "Atlantiko",
+        australia: "Australya",
+        europe: "Europa",
+        indian: "Indiyano",
+        pacific: "Pasipiko",
+    },
+};
@@ -0,0 +1,419 @@
+//! Compiled-in message catalogs for localized responses.
+//!
+//! Every language is a `static` instance of [`Catalog`]. Since these are plain struct
+//! literals, a translation with a missing key simply fails to compile. Templates may
+//! contain `{name}` placeholders, which are substituted via [`fill`].
+
+use std::collections::HashMap;
+
+mod en;
+mod es;
+mod fil;
+mod pt;
+
+pub use en::EN;
+
+/// Every catalog other than the English default.
+pub static TRANSLATIONS: [&Catalog; 3] = [&es::ES, &pt::PT, &fil::FIL];
+
+pub struct Catalog {
+    /// Discord locales served by this catalog. This may be empty for languages
+    /// which Discord does not (yet) offer.
+    pub locales: &'static [&'static str],
+    pub calendar: Calendar,
+    pub commands: Commands,
+    pub names: Names,
+    pub errors: Errors,
+    pub epoch: Epoch,
+    pub help: Help,
+    pub info: Info,
+    pub regions: Regions,
+}
+
+pub struct Calendar {
+    /// Month names from January to December.
+    pub months: [&'static str; 12],
+    /// Weekday names from Monday to Sunday.
+    pub weekdays: [&'static str; 7],
+    /// Long date with the `{weekday}`, `{day}`, `{month}`, and `{year}` placeholders.
+    pub date: &'static str,
+    /// Short date with the `{day}` and `{month}` placeholders.
+    pub day_month: &'static str,
+}
+
+/// Descriptions of the registered commands and their options.
+pub struct Commands {
+    pub help: &'static str,
+    pub help_command: &'static str,
+    pub info: &'static str,
+    pub epoch: &'static str,
+    pub epoch_timezone: &'static str,
+    pub epoch_year: &'static str,
+    pub epoch_month: &'static str,
+    pub epoch_day: &'static str,
+    pub epoch_hour: &'static str,
+    pub epoch_minute: &'static str,
+    pub epoch_second: &'static str,
+    pub epoch_preview: &'static str,
+    pub countdown: &'static str,
+    pub countdown_to: &'static str,
+    pub countdown_timezone: &'static str,
+    pub countdown_title: &'static str,
+    pub birthday: &'static str,
+    pub birthday_set: &'static str,
+    pub birthday_date: &'static str,
+    pub birthday_timezone: &'static str,
+    pub birthday_fallback: &'static str,
+    pub birthday_unset: &'static str,
+    pub dst_alerts: &'static str,
+    pub dst_subscribe: &'static str,
+    pub dst_subscribe_zone: &'static str,
+    pub dst_lead: &'static str,
+    pub dst_unsubscribe: &'static str,
+    pub dst_unsubscribe_zone: &'static str,
+    pub abbr: &'static str,
+    pub abbr_lookup: &'static str,
+    pub abbr_lookup_abbreviation: &'static str,
+    pub abbr_zone: &'static str,
+    pub abbr_zone_zone: &'static str,
+    pub zones: &'static str,
+    pub zones_favorite: &'static str,
+    pub zones_favorite_zone: &'static str,
+    pub zones_unfavorite: &'static str,
+    pub zones_unfavorite_zone: &'static str,
+    pub zones_history: &'static str,
+    pub zones_history_enabled: &'static str,
+}
+
+/// Names of the registered commands, subcommands, and options as shown to the users. These
+/// must be lowercase and unique among their siblings. Interactions always carry the English
+/// names regardless.
+pub struct Names {
+    pub epoch: &'static str,
+    pub help: &'static str,
+    pub info: &'static str,
+    pub countdown: &'static str,
+    pub birthday: &'static str,
+    pub dst_alerts: &'static str,
+    pub abbr: &'static str,
+    pub zones: &'static str,
+    pub timezone: &'static str,
+    pub year: &'static str,
+    pub month: &'static str,
+    pub day: &'static str,
+    pub hour: &'static str,
+    pub minute: &'static str,
+    pub second: &'static str,
+    pub preview: &'static str,
+    pub command: &'static str,
+    pub to: &'static str,
+    pub title: &'static str,
+    pub set: &'static str,
+    pub date: &'static str,
+    pub fallback: &'static str,
+    pub unset: &'static str,
+    pub subscribe: &'static str,
+    pub zone: &'static str,
+    pub lead: &'static str,
+    pub unsubscribe: &'static str,
+    pub lookup: &'static str,
+    pub abbreviation: &'static str,
+    pub favorite: &'static str,
+    pub unfavorite: &'static str,
+    pub history: &'static str,
+    pub enabled: &'static str,
+}
+
+impl Names {
+    /// Selects the translation of the English `name` (if it is registered at all).
+    pub fn selector(name: &str) -> Option<fn(&Names) -> &'static str> {
+        let select: fn(&Names) -> &'static str = match name {
+            "epoch" => |names| names.epoch,
+            "help" => |names| names.help,
+            "info" => |names| names.info,
+            "countdown" => |names| names.countdown,
+            "birthday" => |names| names.birthday,
+            "dst-alerts" => |names| names.dst_alerts,
+            "abbr" => |names| names.abbr,
+            "zones" => |names| names.zones,
+            "timezone" => |names| names.timezone,
+            "year" => |names| names.year,
+            "month" => |names| names.month,
+            "day" => |names| names.day,
+            "hour" => |names| names.hour,
+            "minute" => |names| names.minute,
+            "second" => |names| names.second,
+            "preview" => |names| names.preview,
+            "command" => |names| names.command,
+            "to" => |names| names.to,
+            "title" => |names| names.title,
+            "set" => |names| names.set,
+            "date" => |names| names.date,
+            "fallback" => |names| names.fallback,
+            "unset" => |names| names.unset,
+            "subscribe" => |names| names.subscribe,
+            "zone" => |names| names.zone,
+            "lead" => |names| names.lead,
+            "unsubscribe" => |names| names.unsubscribe,
+            "lookup" => |names| names.lookup,
+            "abbreviation" => |names| names.abbreviation,
+            "favorite" => |names| names.favorite,
+            "unfavorite" => |names| names.unfavorite,
+            "history" => |names| names.history,
+            "enabled" => |names| names.enabled,
+            _ => return None,
+        };
+        Some(select)
+    }
+}
+
+pub struct Errors {
+    /// Ambiguous timezone abbreviation with the `{abbreviation}` and `{candidates}`
+    /// placeholders, the latter of which is a bulleted list.
+    pub ambiguous_timezone: &'static str,
+    pub cannot_post_publicly: &'static str,
+    pub fatal: &'static str,
+    pub invalid_args: &'static str,
+    pub invalid_component: &'static str,
+    pub invalid_date: &'static str,
+    pub invalid_date_time: &'static str,
+    /// Invalid modal input with the `{field}` and `{hint}` placeholders.
+    pub invalid_field: &'static str,
+    /// Invalid command option with the `{option}` placeholder.
+    pub invalid_option: &'static str,
+    pub in_past: &'static str,
+    pub missing_channel: &'static str,
+    pub missing_payload: &'static str,
+    pub missing_required: &'static str,
+    /// Missing command option with the `{option}` placeholder.
+    pub missing_option: &'static str,
+    pub unknown_command: &'static str,
+    pub unregistered_command: &'static str,
+    pub unknown_timezone: &'static str,
+    pub unsupported_interaction_type: &'static str,
+    pub out_of_range: &'static str,
+    /// Command option out of range with the `{option}`, `{min}`, and `{max}` placeholders.
+    pub option_out_of_range: &'static str,
+    pub storage: &'static str,
+}
+
+/// Text input of a modal.
+pub struct Input {
+    pub label: &'static str,
+    pub description: &'static str,
+    /// Shown when the submitted value is invalid.
+    pub hint: &'static str,
+}
+
+pub struct Epoch {
+    pub title: &'static str,
+    pub intro: &'static str,
+    /// Selected date with the `{date}`, `{time}`, `{zone}`, and `{offset}` placeholders.
+    pub showing: &'static str,
+    /// Names of the `t`, `T`, `d`, `D`, `f`, `F`, and `R` styles (in that order).
+    pub styles: [&'static str; 7],
+    /// Labels of the buttons for shifting by −1 day, −1 hour, +1 hour, and +1 day.
+    pub shifts: [&'static str; 4],
+    pub style_placeholder: &'static str,
+    pub zone_placeholder: &'static str,
+    /// Label of the button which posts the timestamp publicly.
+    pub share: &'static str,
+    /// Publicly posted timestamp with the `{timestamp}` and `{user}` placeholders.
+    pub shared: &'static str,
+    pub date: Input,
+    pub time: Input,
+    pub timezone: Input,
+}
+
+pub struct Help {
+    pub menu_title: &'static str,
+    pub menu_description: &'static str,
+    pub menu_help: &'static str,
+    pub menu_epoch: &'static str,
+    pub epoch_title: &'static str,
+    pub epoch_description: &'static str,
+    pub epoch_timezone: &'static str,
+    pub epoch_year: &'static str,
+    /// Month range with the `{first}` and `{last}` month name placeholders.
+    pub epoch_month: &'static str,
+    pub epoch_day: &'static str,
+    pub epoch_hour: &'static str,
+    pub epoch_minute: &'static str,
+    pub epoch_second: &'static str,
+    pub epoch_preview: &'static str,
+    pub help_title: &'static str,
+    pub help_description: &'static str,
+    pub help_epoch: &'static str,
+    pub help_help: &'static str,
+    pub info_title: &'static str,
+    pub info_description: &'static str,
+    pub info_info: &'static str,
+}
+
+pub struct Info {
+    pub description: &'static str,
+    pub install: &'static str,
+    pub report: &'static str,
+    pub fork: &'static str,
+}
+
+/// Names of the regions (i.e., the first path segment) of the timezones.
+pub struct Regions {
+    pub africa: &'static str,
+    pub america: &'static str,
+    pub antarctica: &'static str,
+    pub arctic: &'static str,
+    pub asia: &'static str,
+    pub atlantic: &'static str,
+    pub australia: &'static str,
+    pub europe: &'static str,
+    pub indian: &'static str,
+    pub pacific: &'static str,
+}
+
+impl Regions {
+    /// Labels the `zone` with its region translated (e.g., `America/Sao Paulo` as
+    /// `América/Sao Paulo`). Unknown regions are kept as is.
+    pub fn label(&self, zone: &str) -> String {
+        let translated = zone.split_once('/').and_then(|(region, rest)| {
+            let region = match region {
+                "Africa" => self.africa,
+                "America" => self.america,
+                "Antarctica" => self.antarctica,
+                "Arctic" => self.arctic,
+                "Asia" => self.asia,
+                "Atlantic" => self.atlantic,
+                "Australia" => self.australia,
+                "Europe" => self.europe,
+                "Indian" => self.indian,
+                "Pacific" => self.pacific,
+                _ => return None,
+            };
+            Some(format!("{region}/{rest}"))
+        });
+        translated.as_deref().unwrap_or(zone).replace('_', " ")
+    }
+}
+
+impl Calendar {
+    /// Formats the `date` in long form (e.g., "Thursday, January 31, 2030").
+    pub fn format(&self, date: jiff::civil::Date) -> String {
+        let weekday =
+            self.weekdays[usize::from(date.weekday().to_monday_zero_offset().unsigned_abs())];
+        let month = self.months[usize::from(date.month().unsigned_abs()) - 1];
+        fill(
+            self.date,
+            &[
+                ("weekday", weekday),
+                ("day", &date.day().to_string()),
+                ("month", month),
+                ("year", &date.year().to_string()),
+            ],
+        )
+    }
+}
+
+/// Finds the catalog for a Discord locale (e.g., `es-ES` or `pt-BR`) by its language.
+/// Note that Discord does not offer a Filipino locale yet, so it is matched pre-emptively.
+fn find(locale: &str) -> Option<&'static Catalog> {
+    let lang = locale.split_once('-').map_or(locale, |(lang, _)| lang);
+    Some(match lang {
+        "en" => &EN,
+        "es" => &es::ES,
+        "pt" => &pt::PT,
+        "fil" | "tl" => &fil::FIL,
+        _ => return None,
+    })
+}
+
+/// Selects the catalog for the user's `locale`, falling back to the `guild_locale`
+/// and then to English.
+pub fn select(locale: Option<&str>, guild_locale: Option<&str>) -> &'static Catalog {
+    locale.and_then(find).or_else(|| guild_locale.and_then(find)).unwrap_or(&EN)
+}
+
+/// Localizes the label of the `zone` for every Discord locale in which it differs
+/// from the English label.
+pub fn zone_localizations(zone: &str) -> Option<HashMap<String, String>> {
+    let default = EN.regions.label(zone);
+    let localizations: HashMap<_, _> = TRANSLATIONS
+        .iter()
+        .flat_map(|catalog| {
+            let label = catalog.regions.label(zone);
+            catalog.locales.iter().map(move |locale| (String::from(*locale), label.clone()))
+        })
+        .filter(|(_, label)| *label != default)
+        .collect();
+    (!localizations.is_empty()).then_some(localizations)
+}
+
+/// Substitutes the `{name}` placeholders in the `template` with their values.
+pub fn fill(template: &str, args: &[(&str, &str)]) -> String {
+    args.iter().fold(String::from(template), |text, (name, value)| {
+        text.replace(&format!("{{{name}}}"), value)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Catalog, en::EN, es::ES, fil::FIL, pt::PT};
+
+    /// Extracts the sorted placeholder names in the `template`.
+    fn placeholders(template: &str) -> Vec<&str> {
+        let mut names: Vec<_> = template
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    fn templates(catalog: &Catalog) -> [&'static str; 5] {
+        [
+            catalog.calendar.date,
+            catalog.errors.ambiguous_timezone,
+            catalog.errors.invalid_field,
+            catalog.epoch.showing,
+            catalog.help.epoch_month,
+        ]
+    }
+
+    #[test]
+    fn translations_keep_placeholders() {
+        for catalog in [&ES, &PT, &FIL] {
+            for (expected, actual) in templates(&EN).into_iter().zip(templates(catalog)) {
+                assert_eq!(placeholders(expected), placeholders(actual), "{actual}");
+            }
+        }
+    }
+
+    #[test]
+    fn selects_with_fallback() {
+        assert!(core::ptr::eq(super::select(Some("es-419"), None), &ES));
+        assert!(core::ptr::eq(super::select(Some("pt-BR"), Some("es-ES")), &PT));
+        assert!(core::ptr::eq(super::select(Some("fr"), Some("pt-BR")), &PT));
+        assert!(core::ptr::eq(super::select(Some("fr"), Some("de")), &EN));
+        assert!(core::ptr::eq(super::select(None, None), &EN));
+        assert_eq!(super::fill("{a} and {b}", &[("a", "x"), ("b", "y")]), "x and y");
+    }
+
+    #[test]
+    fn formats_long_dates() {
+        let date = jiff::civil::date(2030, 1, 31);
+        assert_eq!(EN.calendar.format(date), "Thursday, January 31, 2030");
+        assert_eq!(ES.calendar.format(date), "jueves, 31 de enero de 2030");
+        assert_eq!(PT.calendar.format(date), "quinta-feira, 31 de janeiro de 2030");
+        assert_eq!(FIL.calendar.format(date), "Huwebes, Enero 31, 2030");
+    }
+
+    #[test]
+    fn localizes_zone_labels() {
+        assert_eq!(EN.regions.label("America/Sao_Paulo"), "America/Sao Paulo");
+        let localizations = super::zone_localizations("America/Sao_Paulo").unwrap();
+        assert_eq!(localizations["es-419"], "América/Sao Paulo");
+        assert_eq!(localizations["pt-BR"], "América/Sao Paulo");
+        assert!(!super::zone_localizations("Asia/Tokyo").unwrap().contains_key("es-ES"));
+        assert!(super::zone_localizations("UTC").is_none());
+    }
+}
@@ -0,0 +1,207 @@
+use super::{Calendar, Catalog, Commands, Epoch, Errors, Help, Info, Input, Names, Regions};
+
+pub static PT: Catalog = Catalog {
+    locales: &["pt-BR"],
+    calendar: Calendar {
+        months: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        weekdays: [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        date: "{weekday}, {day} de {month} de {year}",
+        day_month: "{day} de {month}",
+    },
+    commands: Commands {
+        help: "Abre o menu de ajuda.",
+        help_command: "Peça mais detalhes sobre um comando específico.",
+        info: "Veja links e informações importantes sobre o app.",
+        epoch: "Obtenha o carimbo de data/hora ISO-8601 de uma data e fuso horário.",
+        epoch_timezone: "O fuso horário da data. Com sugestões automáticas.",
+        epoch_year: "Define o ano. Omita todas as opções de data para digitá-la em um formulário.",
+        epoch_month: "Define o mês (padrão: janeiro).",
+        epoch_day: "Define o dia (padrão: o primeiro dia).",
+        epoch_hour: "Define a hora no formato de 24 horas (padrão: hora 0).",
+        epoch_minute: "Define o minuto (padrão: 0).",
+        epoch_second: "Define o segundo (padrão: 0).",
+        epoch_preview: "Ativa a pré-visualização de todos os formatos de carimbo de data/hora. Ativada por padrão.",
+        countdown: "Publica uma mensagem com contagem regressiva até uma data e hora.",
+        countdown_to: "A data e hora alvo no formato YYYY-MM-DD HH:MM.",
+        countdown_timezone: "O fuso horário da data e hora alvo.",
+        countdown_title: "Para que é a contagem regressiva.",
+        birthday: "Gerencia os anúncios de aniversário neste canal.",
+        birthday_set: "Anuncia o seu aniversário neste canal quando o dia começar no seu fuso horário.",
+        birthday_date: "Seu aniversário no formato MM-DD (ex.: 02-29).",
+        birthday_timezone: "Seu fuso horário (padrão: UTC).",
+        birthday_fallback: "Quando comemorar aniversários de 29 de fevereiro em anos não bissextos.",
+        birthday_unset: "Para de anunciar o seu aniversário neste canal.",
+        dst_alerts: "Gerencia os alertas de horário de verão deste canal.",
+        dst_subscribe: "Publica um aviso neste canal antes de cada mudança de horário de um fuso horário.",
+        dst_subscribe_zone: "O fuso horário a acompanhar.",
+        dst_lead: "Com quantos dias de antecedência publicar o aviso.",
+        dst_unsubscribe: "Para de publicar avisos de um fuso horário neste canal.",
+        dst_unsubscribe_zone: "O fuso horário que deixará de ser acompanhado.",
+        abbr: "Consulta abreviações de fusos horários.",
+        abbr_lookup: "Lista os fusos que usam (ou usaram) uma abreviação.",
+        abbr_lookup_abbreviation: "A abreviação a consultar (p. ex., PST).",
+        abbr_zone: "Lista as abreviações que um fuso horário usa (ou usou).",
+        abbr_zone_zone: "O fuso horário cujas abreviações serão listadas.",
+        zones: "Gerencia os fusos horários sugeridos primeiro para você.",
+        zones_favorite: "Fixa um fuso horário para que seja sempre sugerido primeiro.",
+        zones_favorite_zone: "O fuso horário a fixar.",
+        zones_unfavorite: "Desafixa um dos seus fusos horários favoritos.",
+        zones_unfavorite_zone: "O fuso horário a desafixar.",
+        zones_history: "Ativa ou desativa as sugestões dos seus fusos horários recentes.",
+        zones_history_enabled: "Se os seus fusos horários usados recentemente são registrados.",
+    },
+    names: Names {
+        epoch: "época",
+        help: "ajuda",
+        info: "info",
+        countdown: "contagem-regressiva",
+        birthday: "aniversário",
+        dst_alerts: "alertas-horário-verão",
+        abbr: "abrev",
+        zones: "fusos",
+        timezone: "fuso-horário",
+        year: "ano",
+        month: "mês",
+        day: "dia",
+        hour: "hora",
+        minute: "minuto",
+        second: "segundo",
+        preview: "prévia",
+        command: "comando",
+        to: "até",
+        title: "título",
+        set: "definir",
+        date: "data",
+        fallback: "alternativa",
+        unset: "remover",
+        subscribe: "inscrever",
+        zone: "fuso",
+        lead: "antecedência",
+        unsubscribe: "desinscrever",
+        lookup: "buscar",
+        abbreviation: "abreviação",
+        favorite: "favoritar",
+        unfavorite: "desfavoritar",
+        history: "histórico",
+        enabled: "ativado",
+    },
+    errors: Errors {
+        ambiguous_timezone: "**{abbreviation}** corresponde a fusos diferentes em lugares diferentes. Por favor, informe um destes fusos horários (ou um deslocamento UTC):\n{candidates}",
+        cannot_post_publicly: "Não tenho permissão para publicar aqui. Por favor, copie o carimbo de data/hora manualmente.",
+        fatal: "Erro irrecuperável. Este comportamento é inesperado. Por favor, relate o bug.",
+        invalid_args: "Argumentos do comando inválidos.",
+        invalid_component: "Esta mensagem não é mais interativa. Por favor, use o comando novamente.",
+        invalid_date: "Data inválida. Por favor, use o formato `MM-DD` (ex.: `02-29`).",
+        invalid_date_time: "Data e hora inválidas. Por favor, use o formato `YYYY-MM-DD HH:MM` (ex.: `2030-01-01 09:30`).",
+        invalid_field: "Valor inválido no campo **{field}**. {hint}",
+        invalid_option: "Valor inválido para a opção `{option}`.",
+        in_past: "A data e hora informadas já passaram.",
+        missing_channel: "Este comando deve ser usado dentro de um canal.",
+        missing_payload: "Nenhum dado de interação presente.",
+        missing_required: "Argumentos obrigatórios não informados.",
+        missing_option: "A opção `{option}` é obrigatória.",
+        unknown_command: "Nome de comando desconhecido.",
+        unregistered_command: "Este comando está desatualizado. Por favor, recarregue o Discord para atualizar a lista de comandos e tente novamente.",
+        unknown_timezone: "Fuso horário desconhecido. Informe um nome da IANA (ex.: `America/Sao_Paulo`), um deslocamento UTC (ex.: `UTC-03:00`) ou uma abreviação (ex.: `CEST`).",
+        unsupported_interaction_type: "Tipo de interação não suportado.",
+        out_of_range: "Um valor está fora do intervalo. Ele é grande ou pequeno demais.",
+        option_out_of_range: "A opção `{option}` deve estar entre {min} e {max}.",
+        storage: "Não foi possível salvar suas alterações. Por favor, tente novamente mais tarde.",
+    },
+    epoch: Epoch {
+        title: "Pré-visualização do carimbo de data/hora",
+        intro: "Estas são as possíveis formas de formatar o seu carimbo de data/hora.",
+        showing: "Mostrando {date} às `{time}` em **{zone}** ({offset}).",
+        styles: [
+            "Hora curta",
+            "Hora longa",
+            "Data curta",
+            "Data longa",
+            "Data e hora curtas",
+            "Data e hora longas",
+            "Relativo",
+        ],
+        shifts: ["−1 dia", "−1 hora", "+1 hora", "+1 dia"],
+        style_placeholder: "Trocar o estilo do carimbo de data/hora",
+        zone_placeholder: "Manter a data e hora, mas em outro fuso horário",
+        share: "📢 Publicar para todos",
+        shared: "{timestamp} (compartilhado por {user})",
+        date: Input {
+            label: "Data",
+            description: "Deve estar no formato YYYY-MM-DD.",
+            hint: "Por favor, use o formato `YYYY-MM-DD` (ex.: `2030-01-31`).",
+        },
+        time: Input {
+            label: "Hora",
+            description: "Deve estar no formato de 24 horas HH:MM ou HH:MM:SS. O padrão é meia-noite.",
+            hint: "Por favor, use o formato de 24 horas `HH:MM` ou `HH:MM:SS` (ex.: `13:30`).",
+        },
+        timezone: Input {
+            label: "Fuso horário",
+            description: "Um nome da IANA, um deslocamento UTC ou uma abreviação.",
+            hint: "Por favor, informe um nome da IANA (ex.: `America/Sao_Paulo`), um deslocamento UTC (ex.: `UTC-03:00`) ou uma abreviação (ex.: `CEST`).",
+        },
+    },
+    help: Help {
+        menu_title: "Ajuda do Chrozone",
+        menu_description: "Lista de comandos disponíveis e seus argumentos.",
+        menu_help: "Abre este menu de ajuda.",
+        menu_epoch: "Obtenha o carimbo de data/hora ISO-8601 (em segundos) de uma data e fuso horário.",
+        epoch_title: "Comando `/epoch`",
+        epoch_description: "Gera o carimbo de data/hora ISO-8601 de uma data e fuso horário.",
+        epoch_timezone: "Obrigatório quando alguma opção de data é informada. Deve ser um fuso horário oficialmente registrado no banco de dados de fusos horários da IANA. Por conveniência, há sugestões automáticas.",
+        epoch_year: "Obrigatório quando alguma outra opção de data é informada. Deve ser um ano razoavelmente válido. Se todas as opções de data forem omitidas, um formulário para digitar a data e hora como texto é exibido.",
+        epoch_month: "Deve ser um valor de `1` (padrão) a `12`, onde `1` é {first} e `12` é {last}.",
+        epoch_day: "Deve ser um valor de `1` (padrão) a `31`. Note que os dias `29` a `31` são inválidos apenas em certos meses.",
+        epoch_hour: "Deve ser um valor de `0` (padrão) a `23` (ou seja, formato de 24 horas), onde `0` é `12am` e `23` é `11pm`",
+        epoch_minute: "Deve ser um valor de `0` (padrão) a `59`.",
+        epoch_second: "Deve ser um valor de `0` (padrão) a `60`. O 60º segundo considera possíveis segundos bissextos.",
+        epoch_preview: "Ativa a pré-visualização de todos os formatos de carimbo de data/hora. O padrão é `true`.",
+        help_title: "Comando `/help`",
+        help_description: "Fornece mais detalhes sobre comandos específicos.",
+        help_epoch: "Mostra mais informações sobre cada argumento do comando `/epoch`.",
+        help_help: "Fornece mais detalhes sobre como usar o comando `/help`.",
+        info_title: "Informações do Chrozone",
+        info_description: "Mostra informações importantes sobre o app: link de convite, relato de bugs e código-fonte.",
+        info_info: "Mostra a página de informações.",
+    },
+    info: Info {
+        description: "Chrozone é um bot de [código aberto](https://github.com/BastiDood/chrozone) escrito em [Rust](https://www.rust-lang.org/) por [`@BastiDood`](https://github.com/BastiDood) com utilitários de fusos horários e formatação de carimbos de data/hora.",
+        install: "Instalar o app",
+        report: "Relatar um bug",
+        fork: "Fazer um fork do código",
+    },
+    regions: Regions {
+        africa: "África",
+        america: "América",
+        antarctica: "Antártida",
+        arctic: "Ártico",
+        asia: "Ásia",
+        atlantic: "Atlântico",
+        australia: "Austrália",
+        europe: "Europa",
+        indian: "Índico",
+        pacific: "Pacífico",
+    },
+};
@@ -0,0 +1,306 @@
+//! Two-level region/zone select menus for picking a timezone without knowing its name.
+//!
+//! The flow is started by some other component (the "origin") whose `custom_id` is carried
+//! along as trailing fields of every `custom_id` in the flow. The final zone menu reuses
+//! the origin's `custom_id` verbatim so that the chosen zone is dispatched to the origin's
+//! handler exactly as if it had been picked from the origin's own zone menu.
+
+use super::{custom_id, error};
+use jiff::{Timestamp, tz::TimeZone};
+use std::sync::LazyLock;
+use twilight_model::{
+    channel::message::{
+        Component,
+        component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption, SelectMenuType},
+    },
+    http::interaction::InteractionResponseData,
+};
+
+/// Maximum number of options in a select menu as imposed by Discord.
+const PAGE_SIZE: usize = 25;
+
+/// Region name for zones without a `/` in their name (e.g., `UTC`).
+const OTHER: &str = "Other";
+
+/// A region name along with the (sorted) zones in it.
+type Region = (Box<str>, Box<[Box<str>]>);
+
+/// Every available zone grouped by its region (i.e., the first path segment).
+static REGIONS: LazyLock<Box<[Region]>> = LazyLock::new(|| {
+    let mut regions = std::collections::BTreeMap::<Box<str>, Vec<Box<str>>>::new();
+    for zone in jiff::tz::db().available() {
+        let zone = zone.as_str();
+        let region = zone.split_once('/').map_or(OTHER, |(region, _)| region);
+        regions.entry(region.into()).or_default().push(zone.into());
+    }
+    regions
+        .into_iter()
+        .take(PAGE_SIZE)
+        .map(|(region, mut zones)| {
+            zones.sort_unstable();
+            (region, zones.into_boxed_slice())
+        })
+        .collect()
+});
+
+fn encode(fields: &[&str]) -> error::Result<String> {
+    custom_id::encode("browse", fields).map_err(|err| {
+        log::error!("Failed to encode custom ID: {err:?}.");
+        error::Error::Fatal
+    })
+}
+
+/// Prefixes the origin fields with the given browse fields.
+fn with_origin<'a>(fields: &[&'a str], origin: &[&'a str]) -> Vec<&'a str> {
+    fields.iter().chain(origin).copied().collect()
+}
+
+/// Creates a button which starts the flow on behalf of the `origin`, which is the `kind`
+/// and fields of the origin's zone menu `custom_id`.
+pub fn start_button(origin: &[&str]) -> error::Result<Component> {
+    Ok(Component::Button(Button {
+        id: None,
+        custom_id: Some(encode(&with_origin(&["start"], origin))?),
+        disabled: false,
+        emoji: None,
+        label: Some(String::from("🌐 More")),
+        style: ButtonStyle::Primary,
+        url: None,
+        sku_id: None,
+    }))
+}
+
+fn region_menu(origin: &[&str], selected: Option<&str>) -> error::Result<Component> {
+    Ok(Component::SelectMenu(SelectMenu {
+        id: None,
+        channel_types: None,
+        custom_id: encode(&with_origin(&["region"], origin))?,
+        default_values: None,
+        disabled: false,
+        kind: SelectMenuType::Text,
+        max_values: Some(1),
+        min_values: Some(1),
+        options: Some(
+            REGIONS
+                .iter()
+                .map(|(region, zones)| SelectMenuOption {
+                    default: Some(region.as_ref()) == selected,
+                    description: Some(format!("{} timezones", zones.len())),
+                    emoji: None,
+                    label: region.replace('_', " "),
+                    value: String::from(region.as_ref()),
+                })
+                .collect(),
+        ),
+        placeholder: Some(String::from("Pick a region")),
+        required: None,
+    }))
+}
+
+fn zone_menu(
+    origin: &[&str],
+    region: &str,
+    zones: &[Box<str>],
+    placeholder: String,
+    now: Timestamp,
+) -> error::Result<Component> {
+    let (kind, fields) = origin.split_first().ok_or(error::Error::InvalidComponent)?;
+    let custom_id = custom_id::encode(kind, fields).map_err(|err| {
+        log::error!("Failed to encode custom ID: {err:?}.");
+        error::Error::Fatal
+    })?;
+
+    let options = zones
+        .iter()
+        .map(|zone| {
+            let description = TimeZone::get(zone).ok().map(|tz| {
+                let time = now.to_zoned(tz.clone()).strftime("%H:%M");
+                format!("{} · {time}", crate::util::describe_offset(&tz, now))
+            });
+            let label = zone.strip_prefix(region).and_then(|rest| rest.strip_prefix('/'));
+            SelectMenuOption {
+                default: false,
+                description,
+                emoji: None,
+                label: label.unwrap_or(zone).replace('_', " "),
+                value: String::from(zone.as_ref()),
+            }
+        })
+        .collect();
+
+    Ok(Component::SelectMenu(SelectMenu {
+        id: None,
+        channel_types: None,
+        custom_id,
+        default_values: None,
+        disabled: false,
+        kind: SelectMenuType::Text,
+        max_values: Some(1),
+        min_values: Some(1),
+        options: Some(options),
+        placeholder: Some(placeholder),
+        required: None,
+    }))
+}
+
+fn page_button(
+    origin: &[&str],
+    region: &str,
+    page: usize,
+    label: &str,
+    disabled: bool,
+) -> error::Result<Component> {
+    let page = page.to_string();
+    Ok(Component::Button(Button {
+        id: None,
+        custom_id: Some(encode(&with_origin(&["page", region, &page], origin))?),
+        disabled,
+        emoji: None,
+        label: Some(String::from(label)),
+        style: ButtonStyle::Secondary,
+        url: None,
+        sku_id: None,
+    }))
+}
+
+/// Renders the menus for the given `page` of the `region`.
+fn render_region(
+    origin: &[&str],
+    region: &str,
+    page: usize,
+    now: Timestamp,
+) -> error::Result<InteractionResponseData> {
+    let Some((region, zones)) = REGIONS.iter().find(|(other, _)| other.as_ref() == region) else {
+        log::error!("Unknown region {region}.");
+        return Err(error::Error::InvalidComponent);
+    };
+
+    let pages = zones.len().div_ceil(PAGE_SIZE);
+    let page = page.min(pages - 1);
+    let chunk = zones.chunks(PAGE_SIZE).nth(page).unwrap_or_default();
+    let placeholder = format!("Pick a timezone in {region} (page {} of {pages})", page + 1);
+
+    let mut components = Vec::from([
+        Component::ActionRow(ActionRow {
+            id: None,
+            components: Vec::from([region_menu(origin, Some(region))?]),
+        }),
+        Component::ActionRow(ActionRow {
+            id: None,
+            components: Vec::from([zone_menu(origin, region, chunk, placeholder, now)?]),
+        }),
+    ]);
+    if pages > 1 {
+        let buttons = Vec::from([
+            page_button(origin, region, page.saturating_sub(1), "◀ Previous", page == 0)?,
+            page_button(origin, region, page + 1, "Next ▶", page + 1 >= pages)?,
+        ]);
+        components.push(Component::ActionRow(ActionRow { id: None, components: buttons }));
+    }
+
+    Ok(InteractionResponseData { components: Some(components), ..Default::default() })
+}
+
+/// Handler for the components of the browsing flow. The `fields` are those decoded from
+/// the `custom_id` (excluding the kind) whereas the `values` are the selected options.
+pub fn on_component(
+    fields: &[&str],
+    values: &[String],
+    now: Timestamp,
+) -> error::Result<InteractionResponseData> {
+    match fields {
+        ["start", origin @ ..] => Ok(InteractionResponseData {
+            components: Some(Vec::from([Component::ActionRow(ActionRow {
+                id: None,
+                components: Vec::from([region_menu(origin, None)?]),
+            })])),
+            ..Default::default()
+        }),
+        ["region", origin @ ..] => {
+            let region = values.first().ok_or(error::Error::InvalidComponent)?;
+            render_region(origin, region, 0, now)
+        }
+        ["page", region, page, origin @ ..] => {
+            let page = page.parse().map_err(|_| error::Error::InvalidComponent)?;
+            render_region(origin, region, page, now)
+        }
+        other => {
+            log::error!("Unexpected browse component fields {other:?}.");
+            Err(error::Error::InvalidComponent)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::custom_id, PAGE_SIZE, REGIONS};
+    use twilight_model::channel::message::Component;
+
+    const ORIGIN: [&str; 5] =
+        ["epoch", "zone", "-9223372036854775808", "America/Argentina/ComodRivadavia", "f"];
+
+    fn menus(
+        components: &[Component],
+    ) -> Vec<&twilight_model::channel::message::component::SelectMenu> {
+        components
+            .iter()
+            .flat_map(|row| match row {
+                Component::ActionRow(row) => row.components.as_slice(),
+                _ => &[],
+            })
+            .filter_map(|component| match component {
+                Component::SelectMenu(menu) => Some(menu),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_page_fits() {
+        let now = jiff::Timestamp::UNIX_EPOCH;
+        assert!(REGIONS.len() <= PAGE_SIZE);
+        for (region, zones) in REGIONS.iter() {
+            for page in 0..zones.len().div_ceil(PAGE_SIZE) {
+                let page = page.to_string();
+                let data = super::on_component(
+                    &[&["page", region, &page][..], &ORIGIN].concat(),
+                    &[],
+                    now,
+                )
+                .unwrap();
+                let components = data.components.unwrap();
+                let menus = menus(&components);
+                let options = menus[1].options.as_ref().unwrap();
+                assert!(!options.is_empty() && options.len() <= PAGE_SIZE);
+                assert!(options.iter().all(|option| option.label.len() <= 100));
+            }
+        }
+    }
+
+    #[test]
+    fn final_menu_targets_origin() {
+        let now = jiff::Timestamp::UNIX_EPOCH;
+        let data = super::on_component(
+            &[&["region"][..], &ORIGIN].concat(),
+            &[String::from("Australia")],
+            now,
+        )
+        .unwrap();
+        let components = data.components.unwrap();
+        let menus = menus(&components);
+        let (kind, fields) = custom_id::decode(&menus[1].custom_id).unwrap();
+        assert_eq!(kind, "epoch");
+        assert_eq!(fields, ORIGIN[1..]);
+
+        let perth = menus[1]
+            .options
+            .as_ref()
+            .unwrap()
+            .iter()
+            .find(|option| option.value == "Australia/Perth");
+        let perth = perth.unwrap();
+        assert_eq!(perth.label, "Perth");
+        let description = perth.description.as_deref().unwrap();
+        assert!(description.starts_with("UTC+08:00 (") && description.ends_with(") · 08:00"));
+    }
+}
@@ -0,0 +1,245 @@
+use super::{args, autocomplete, error, schema};
+use crate::util::{
+    abbr::{Abbreviations, Period, Usage},
+    format_offset, score,
+};
+use jiff::{SignedDuration, tz::TimeZone};
+use twilight_model::{
+    application::command::{Command, CommandOptionChoice, CommandOptionChoiceValue},
+    http::interaction::InteractionResponseData,
+};
+
+/// Maximum number of characters in a message, which leaves some headroom below Discord's
+/// limit of 2000 for the trailing note on the omitted entries.
+const MAX_CONTENT: usize = 1900;
+
+args::command_args! {
+    /// Arguments of the `/abbr lookup` subcommand.
+    pub struct LookupArgs {
+        abbreviation: String = "abbreviation",
+    }
+}
+
+args::command_args! {
+    /// Arguments of the `/abbr zone` subcommand. The zone must be a name from the database
+    /// since fixed offsets (and abbreviations) have no abbreviations of their own.
+    pub struct ZoneArgs {
+        zone: String = "zone",
+    }
+}
+
+/// Wraps the `content` as a public reply.
+fn reply(content: String) -> InteractionResponseData {
+    InteractionResponseData { content: Some(content), ..Default::default() }
+}
+
+/// Handler for the `/abbr lookup` subcommand, which lists the zones that used the
+/// abbreviation grouped by their offsets.
+pub fn lookup(
+    LookupArgs { abbreviation }: LookupArgs,
+    index: &Abbreviations,
+) -> error::Result<InteractionResponseData> {
+    let abbreviation = abbreviation.trim().to_ascii_uppercase();
+    let years = index.years();
+    let (first, last) = (years.start(), years.end());
+
+    let mut usages = index.get(&abbreviation);
+    if usages.is_empty() {
+        return Ok(reply(format!(
+            "No timezone used **{abbreviation}** between {first} and {last}."
+        )));
+    }
+    usages.sort_by_key(|usage| usage.offset);
+
+    let header = format!("**{abbreviation}** was used between {first} and {last} as follows:");
+    let mut lines = Vec::<String>::new();
+    let mut last_offset = None;
+    let items = usages.into_iter().map(|usage| {
+        let item = format!("{} ({})", usage.zone.replace('_', " "), span(usage));
+        if last_offset.replace(usage.offset) == Some(usage.offset) {
+            (None, item)
+        } else {
+            (Some(format!("**{}**: ", format_offset(usage.offset))), item)
+        }
+    });
+    let omitted = fit(&header, items, &mut lines);
+    Ok(reply(render(header, lines, omitted)))
+}
+
+/// Handler for the `/abbr zone` subcommand, which lists the abbreviations that the zone
+/// used in the order of their first use.
+pub fn zone(
+    ZoneArgs { zone }: ZoneArgs,
+    index: &Abbreviations,
+) -> error::Result<InteractionResponseData> {
+    let tz = TimeZone::get(zone.trim()).map_err(|_| error::Error::UnknownTimezone)?;
+    let zone = tz.iana_name().unwrap_or(zone.trim());
+    let years = index.years();
+    let (first, last) = (years.start(), years.end());
+
+    let usages = index.of(zone);
+    if usages.is_empty() {
+        return Ok(reply(format!("No abbreviations of **{zone}** between {first} and {last}.")));
+    }
+
+    let header = format!("**{zone}** used these abbreviations between {first} and {last}:");
+    let mut lines = Vec::new();
+    let items = usages.iter().map(|usage| {
+        let item = format!(
+            "- **{}** ({}): {}",
+            usage.abbreviation,
+            format_offset(usage.offset),
+            span(usage)
+        );
+        (Some(String::new()), item)
+    });
+    let omitted = fit(&header, items, &mut lines);
+    Ok(reply(render(header, lines, omitted)))
+}
+
+/// Appends the `items` into the `lines` until the message would exceed [`MAX_CONTENT`].
+/// Items with a prefix start a new line whereas the others continue the last line. Returns
+/// the number of omitted items.
+fn fit(
+    header: &str,
+    items: impl Iterator<Item = (Option<String>, String)>,
+    lines: &mut Vec<String>,
+) -> usize {
+    let mut length = header.chars().count();
+    let mut omitted = 0;
+    for (prefix, item) in items {
+        let addition = match &prefix {
+            Some(prefix) => 1 + prefix.chars().count() + item.chars().count(),
+            None => 2 + item.chars().count(),
+        };
+        if omitted > 0 || length + addition > MAX_CONTENT {
+            omitted += 1;
+            continue;
+        }
+        length += addition;
+        match (prefix, lines.last_mut()) {
+            (None, Some(line)) => {
+                line.push_str(", ");
+                line.push_str(&item);
+            }
+            (prefix, _) => lines.push(prefix.unwrap_or_default() + &item),
+        }
+    }
+    omitted
+}
+
+fn render(header: String, lines: Vec<String>, omitted: usize) -> String {
+    let mut content = header;
+    for line in lines {
+        content.push('\n');
+        content.push_str(&line);
+    }
+    if omitted > 0 {
+        content.push_str(&format!("\n…and {omitted} more."));
+    }
+    content
+}
+
+/// Renders the years spanned by the periods of the `usage` along with their number (if
+/// there is more than one).
+fn span(usage: &Usage) -> String {
+    let year = |period: &Period, end: bool| {
+        let at = if end { period.end - SignedDuration::from_secs(1) } else { period.start };
+        at.to_zoned(TimeZone::UTC).year()
+    };
+    let (Some(first), Some(last)) = (usage.periods.first(), usage.periods.last()) else {
+        return String::new();
+    };
+    let (start, end) = (year(first, false), year(last, true));
+    let years = if start == end { start.to_string() } else { format!("{start}–{end}") };
+    match usage.periods.len() {
+        1 => years,
+        count => format!("{years}, {count} periods"),
+    }
+}
+
+/// Autocompletes the known abbreviations which contain the letters of the query in order
+/// (e.g., `EST` for `AEST`). Shorter abbreviations win ties.
+pub fn abbreviations(context: &autocomplete::Context) -> Vec<CommandOptionChoice> {
+    let mut names: Vec<_> = context.state.abbreviations.names().collect();
+    names.sort_unstable_by_key(|name| (name.len(), *name));
+    let scorer = &score::Subsequence;
+    score::rank(names, |name| name, context.query, scorer, autocomplete::MAX_CHOICES)
+        .into_iter()
+        .map(|name| CommandOptionChoice {
+            name: String::from(name),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(String::from(name)),
+        })
+        .collect()
+}
+
+/// Registered definition of the `/abbr` command.
+pub fn schema() -> Command {
+    use schema::{autocomplete, option, required, subcommand};
+    use twilight_model::application::command::CommandOptionType::String;
+    schema::command(
+        "abbr",
+        |c| c.abbr,
+        Vec::from([
+            subcommand(
+                "lookup",
+                |c| c.abbr_lookup,
+                Vec::from([required(autocomplete(option(String, "abbreviation", |c| {
+                    c.abbr_lookup_abbreviation
+                })))]),
+            ),
+            subcommand(
+                "zone",
+                |c| c.abbr_zone,
+                Vec::from([required(autocomplete(option(String, "zone", |c| c.abbr_zone_zone)))]),
+            ),
+        ]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LookupArgs, ZoneArgs};
+    use crate::util::abbr::Abbreviations;
+
+    fn content(data: twilight_model::http::interaction::InteractionResponseData) -> String {
+        data.content.unwrap()
+    }
+
+    #[test]
+    fn lookup_both_ways() {
+        let index =
+            &Abbreviations::scan(["Asia/Kolkata", "Europe/Dublin", "Asia/Manila"], 2020..=2021);
+
+        let lookup = |abbreviation: &str| {
+            let args = LookupArgs { abbreviation: abbreviation.into() };
+            content(super::lookup(args, index).unwrap())
+        };
+        assert_eq!(
+            lookup("ist"),
+            "**IST** was used between 2020 and 2021 as follows:\n**UTC+01:00**: Europe/Dublin (2020–2021, 2 periods)\n**UTC+05:30**: Asia/Kolkata (2020–2021)"
+        );
+        assert_eq!(lookup("XYZ"), "No timezone used **XYZ** between 2020 and 2021.");
+
+        let zone =
+            |zone: &str| content(super::zone(ZoneArgs { zone: zone.into() }, index).unwrap());
+        assert_eq!(
+            zone("Europe/Dublin"),
+            "**Europe/Dublin** used these abbreviations between 2020 and 2021:\n- **GMT** (UTC+00:00): 2020–2021, 3 periods\n- **IST** (UTC+01:00): 2020–2021, 2 periods"
+        );
+        assert!(zone("Europe/Paris").starts_with("No abbreviations"));
+        assert!(super::zone(ZoneArgs { zone: "PST".into() }, index).is_err());
+    }
+
+    #[test]
+    fn fit_omits_overflow() {
+        let mut lines = Vec::new();
+        let items = (0..500).map(|i| (None, format!("Zone/{i}")));
+        let omitted = super::fit("Header", items, &mut lines);
+        assert!(omitted > 0);
+        let content = super::render(String::from("Header"), lines, omitted);
+        assert!(content.chars().count() <= 2000);
+        assert!(content.ends_with(&format!("…and {omitted} more.")));
+    }
+}
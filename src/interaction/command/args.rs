@@ -0,0 +1,242 @@
+//! Typed parsing of the options received by commands. Every received value is first
+//! validated against the registered definition of its option (i.e., its type, range,
+//! length, and choices) so that the parser and the registered schema never diverge.
+
+use super::error;
+use twilight_model::application::{
+    command::{CommandOption, CommandOptionChoiceValue, CommandOptionValue as Bound},
+    interaction::application_command::{CommandDataOption, CommandOptionValue},
+};
+
+/// Typed arguments of a (sub)command as parsed from its received options, which the
+/// router has already resolved down to the innermost subcommand (see [`super::route`]).
+/// Usually implemented through the [`command_args`] macro.
+pub trait FromCommandData: Sized {
+    /// Parses the received `options` given the registered definitions in the `schema`.
+    fn from_options(
+        schema: &[CommandOption],
+        options: Vec<CommandDataOption>,
+    ) -> error::Result<Self>;
+}
+
+/// Commands (and subcommands) without any options.
+impl FromCommandData for () {
+    fn from_options(_: &[CommandOption], options: Vec<CommandDataOption>) -> error::Result<Self> {
+        match options.into_iter().next() {
+            Some(CommandDataOption { name, .. }) => {
+                log::error!("Unexpected argument [{name}].");
+                Err(error::Error::InvalidOption(name.into_boxed_str()))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Conversion from the value of a single (already validated) option.
+pub trait FromOption: Sized {
+    fn from_option(name: &str, value: CommandOptionValue) -> error::Result<Self>;
+
+    /// The value to use when the option is absent. Required options have none.
+    fn absent() -> Option<Self> {
+        None
+    }
+}
+
+impl<T: FromOption> FromOption for Option<T> {
+    fn from_option(name: &str, value: CommandOptionValue) -> error::Result<Self> {
+        T::from_option(name, value).map(Some)
+    }
+
+    fn absent() -> Option<Self> {
+        Some(None)
+    }
+}
+
+/// Error for values whose type does not match their registered definition.
+fn mismatch(name: &str, value: &CommandOptionValue) -> error::Error {
+    log::error!("Unexpected value {value:?} for argument [{name}].");
+    error::Error::InvalidOption(name.into())
+}
+
+impl FromOption for String {
+    fn from_option(name: &str, value: CommandOptionValue) -> error::Result<Self> {
+        match value {
+            CommandOptionValue::String(text) => Ok(text),
+            other => Err(mismatch(name, &other)),
+        }
+    }
+}
+
+impl FromOption for bool {
+    fn from_option(name: &str, value: CommandOptionValue) -> error::Result<Self> {
+        match value {
+            CommandOptionValue::Boolean(value) => Ok(value),
+            other => Err(mismatch(name, &other)),
+        }
+    }
+}
+
+macro_rules! integers {
+    ($($ty:ty),*) => {$(
+        impl FromOption for $ty {
+            fn from_option(name: &str, value: CommandOptionValue) -> error::Result<Self> {
+                let CommandOptionValue::Integer(num) = value else {
+                    return Err(mismatch(name, &value));
+                };
+                Self::try_from(num).map_err(|_| error::Error::OptionOutOfRange {
+                    option: name.into(),
+                    min: Self::MIN.into(),
+                    max: Self::MAX.into(),
+                })
+            }
+        }
+    )*};
+}
+
+integers!(i8, i16, u16, i64);
+
+impl FromOption for crate::util::tz::Zone {
+    fn from_option(name: &str, value: CommandOptionValue) -> error::Result<Self> {
+        let text = String::from_option(name, value)?;
+        crate::util::tz::resolve(&text).map_err(|err| {
+            log::error!("Failed to resolve timezone {text}: {err:?}.");
+            err.into()
+        })
+    }
+}
+
+/// Implements [`FromOption`] for a type whose values are the string choices of an option.
+macro_rules! choices {
+    ($ty:ty { $($choice:literal => $value:expr),* $(,)? }) => {
+        impl $crate::interaction::command::args::FromOption for $ty {
+            fn from_option(
+                name: &str,
+                value: ::twilight_model::application::interaction::application_command::CommandOptionValue,
+            ) -> $crate::interaction::error::Result<Self> {
+                let text = <String as $crate::interaction::command::args::FromOption>::from_option(name, value)?;
+                match text.as_str() {
+                    $($choice => Ok($value),)*
+                    other => {
+                        log::error!("Unknown choice {other} for argument [{name}].");
+                        Err($crate::interaction::error::Error::InvalidOption(name.into()))
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Declares a struct of typed arguments along with its [`FromCommandData`] implementation.
+/// Each field names its option and, optionally, the default value if it is absent. Fields
+/// of type `Option<T>` are optional whereas the rest without defaults are required.
+macro_rules! command_args {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field:ident: $ty:ty = $option:expr $(=> $default:expr)?),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($field: $ty,)*
+        }
+
+        impl $crate::interaction::command::args::FromCommandData for $name {
+            fn from_options(
+                schema: &[::twilight_model::application::command::CommandOption],
+                options: Vec<::twilight_model::application::interaction::application_command::CommandDataOption>,
+            ) -> $crate::interaction::error::Result<Self> {
+                use $crate::interaction::{command::args::{self, FromOption}, error::Error};
+                $(let mut $field = None::<$ty>;)*
+                for ::twilight_model::application::interaction::application_command::CommandDataOption { name, value } in options {
+                    log::info!("Received argument [{name}] as {value:?}.");
+                    args::validate(schema, &name, &value)?;
+                    $(if name == $option {
+                        $field = Some(FromOption::from_option($option, value)?);
+                        continue;
+                    })*
+                    log::error!("Unexpected argument [{name}].");
+                    return Err(Error::InvalidOption(name.into_boxed_str()));
+                }
+                Ok(Self {
+                    $($field: $crate::interaction::command::args::command_args!(@finish $field, $option $(, $default)?),)*
+                })
+            }
+        }
+    };
+    (@finish $field:ident, $option:expr) => {
+        match $field.or_else(FromOption::absent) {
+            Some(value) => value,
+            None => return Err(Error::MissingOption($option.into())),
+        }
+    };
+    (@finish $field:ident, $option:expr, $default:expr) => {
+        $field.unwrap_or_else(|| $default)
+    };
+}
+
+pub(crate) use {choices, command_args};
+
+/// Validates the received `value` of the option called `name` against its registered
+/// definition in the `schema`, which is returned on success.
+pub fn validate<'s>(
+    schema: &'s [CommandOption],
+    name: &str,
+    value: &CommandOptionValue,
+) -> error::Result<&'s CommandOption> {
+    let invalid = || error::Error::InvalidOption(name.into());
+    let Some(definition) = schema.iter().find(|option| option.name == name) else {
+        log::error!("Received undefined argument [{name}].");
+        return Err(invalid());
+    };
+
+    if definition.kind != value.kind() {
+        return Err(mismatch(name, value));
+    }
+
+    match value {
+        CommandOptionValue::Integer(num) => {
+            let bound = |bound: Option<Bound>, default| match bound {
+                Some(Bound::Integer(bound)) => bound,
+                _ => default,
+            };
+            let min = bound(definition.min_value, i64::MIN);
+            let max = bound(definition.max_value, i64::MAX);
+            if !(min..=max).contains(num) {
+                log::error!("Argument [{name}] of {num} is out of range.");
+                return Err(error::Error::OptionOutOfRange { option: name.into(), min, max });
+            }
+        }
+        CommandOptionValue::String(text) => {
+            let len = text.chars().count();
+            let min = definition.min_length.map_or(0, usize::from);
+            let max = definition.max_length.map_or(usize::MAX, usize::from);
+            if !(min..=max).contains(&len) {
+                log::error!("Argument [{name}] of length {len} is out of range.");
+                return Err(invalid());
+            }
+        }
+        _ => (),
+    }
+
+    if let Some(choices) = &definition.choices {
+        let chosen = choices.iter().any(|choice| match (&choice.value, value) {
+            (CommandOptionChoiceValue::String(choice), CommandOptionValue::String(value)) => {
+                choice == value
+            }
+            (CommandOptionChoiceValue::Integer(choice), CommandOptionValue::Integer(value)) => {
+                choice == value
+            }
+            (CommandOptionChoiceValue::Number(choice), CommandOptionValue::Number(value)) => {
+                choice == value
+            }
+            _ => false,
+        });
+        if !chosen {
+            log::error!("Argument [{name}] is not one of its choices.");
+            return Err(invalid());
+        }
+    }
+
+    Ok(definition)
+}
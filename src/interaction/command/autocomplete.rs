@@ -0,0 +1,373 @@
+//! Autocompletion providers keyed by the path of the (sub)command and the name of the
+//! focused option. Discord's limits on the choices are enforced here for every provider.
+//! Each provider chooses the [`Scorer`](crate::util::score::Scorer) which suits its items.
+
+use super::{abbr, dst_alerts, help, route, zones};
+use crate::{
+    State,
+    i18n::{self, Catalog},
+};
+use twilight_model::{
+    application::{
+        command::{CommandOptionChoice, CommandOptionChoiceValue},
+        interaction::{
+            Interaction,
+            application_command::{CommandData, CommandDataOption, CommandOptionValue},
+        },
+    },
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+};
+
+/// Maximum number of choices in a response.
+pub const MAX_CHOICES: usize = 25;
+
+/// Maximum number of characters in the name and (string) value of a choice.
+const MAX_LENGTH: usize = 100;
+
+/// Context of an autocompletion request.
+pub struct Context<'a> {
+    /// The text typed so far into the focused option.
+    pub query: &'a str,
+    /// Every option at the same level as the focused one, some of which may already be filled.
+    pub options: &'a [CommandDataOption],
+    pub interaction: &'a Interaction,
+    pub state: &'a State,
+    pub catalog: &'a Catalog,
+}
+
+impl Context<'_> {
+    /// Returns the value of the integer option called `name` if it has been filled.
+    pub fn integer(&self, name: &str) -> Option<i64> {
+        self.options.iter().find_map(|option| match option.value {
+            CommandOptionValue::Integer(value) if option.name == name => Some(value),
+            _ => None,
+        })
+    }
+
+    /// The date and time typed so far into the date options of `/epoch` (with the same
+    /// defaults) if at least the year has been filled.
+    fn datetime(&self) -> Option<jiff::civil::DateTime> {
+        let year = i16::try_from(self.integer("year")?).ok()?;
+        let [month, day, hour, minute, second] =
+            [("month", 1), ("day", 1), ("hour", 0), ("minute", 0), ("second", 0)].map(
+                |(name, default)| {
+                    self.integer(name).and_then(|value| i8::try_from(value).ok()).unwrap_or(default)
+                },
+            );
+        jiff::civil::DateTime::new(year, month, day, hour, minute, second, 0).ok()
+    }
+
+    /// Today's date in UTC.
+    fn today(&self) -> jiff::civil::Date {
+        self.state.clock.now().to_zoned(jiff::tz::TimeZone::UTC).date()
+    }
+}
+
+/// Provider of the choices for an option, which are ordered by relevance.
+type Provider = fn(&Context) -> Vec<CommandOptionChoice>;
+
+/// Providers keyed by the path of their (sub)command and the name of their option.
+static PROVIDERS: [(&str, &str, Provider); 12] = [
+    ("epoch", "timezone", epoch_timezones),
+    ("epoch", "year", years),
+    ("epoch", "day", days),
+    ("help", "command", help::topics),
+    ("countdown", "timezone", timezones),
+    ("birthday set", "timezone", timezones),
+    ("dst-alerts subscribe", "zone", timezones),
+    ("dst-alerts unsubscribe", "zone", dst_alerts::subscriptions),
+    ("abbr lookup", "abbreviation", abbr::abbreviations),
+    ("abbr zone", "zone", timezones),
+    ("zones favorite", "zone", timezones),
+    ("zones unfavorite", "zone", zones::favorites),
+];
+
+/// Responds with the choices for the focused option of the `data`.
+pub fn respond(
+    interaction: &Interaction,
+    data: &CommandData,
+    state: &State,
+    catalog: &Catalog,
+) -> Option<InteractionResponse> {
+    let route::Focused { path, name, value, options } = route::focused(data)?;
+    let Some((.., provider)) =
+        PROVIDERS.iter().find(|(other, option, _)| *other == path && *option == name)
+    else {
+        log::error!("No autocompletions for [{name}] of /{path}.");
+        return None;
+    };
+
+    let context = Context { query: value, options, interaction, state, catalog };
+    let choices = limit(provider(&context));
+    log::info!("Generated {} autocompletions", choices.len());
+    Some(InteractionResponse {
+        kind: InteractionResponseType::ApplicationCommandAutocompleteResult,
+        data: Some(InteractionResponseData { choices: Some(choices), ..Default::default() }),
+    })
+}
+
+/// Enforces Discord's limits on the `choices`. Overlong names are truncated whereas choices
+/// with overlong values are dropped altogether since their values must be exact.
+fn limit(choices: Vec<CommandOptionChoice>) -> Vec<CommandOptionChoice> {
+    choices
+        .into_iter()
+        .filter(|choice| match &choice.value {
+            CommandOptionChoiceValue::String(value) => value.chars().count() <= MAX_LENGTH,
+            _ => true,
+        })
+        .take(MAX_CHOICES)
+        .map(|CommandOptionChoice { name, name_localizations, value }| CommandOptionChoice {
+            name: truncate(name),
+            name_localizations: name_localizations.map(|localizations| {
+                localizations.into_iter().map(|(locale, name)| (locale, truncate(name))).collect()
+            }),
+            value,
+        })
+        .collect()
+}
+
+/// Truncates the `text` to [`MAX_LENGTH`] characters with a trailing ellipsis.
+fn truncate(text: String) -> String {
+    truncate_to(text, MAX_LENGTH)
+}
+
+/// Truncates the `text` to `max` characters with a trailing ellipsis.
+fn truncate_to(mut text: String, max: usize) -> String {
+    if let Some((end, _)) = text.char_indices().nth(max) {
+        let end = text[..end].char_indices().next_back().map_or(0, |(start, _)| start);
+        text.truncate(end);
+        text.push('…');
+    }
+    text
+}
+
+/// Instant for which the offsets of the timezones are described in their labels.
+#[derive(Clone, Copy)]
+enum At {
+    /// The current instant, which is shown in the local time of each zone.
+    Now(jiff::Timestamp),
+    /// The date and time as typed (i.e., in each zone), which is shown as is.
+    Typed(jiff::civil::DateTime),
+}
+
+/// Timezones from the IANA database that are the most similar to the query, labelled
+/// with their current offsets and local times.
+fn timezones(context: &Context) -> Vec<CommandOptionChoice> {
+    timezones_at(context, At::Now(context.state.clock.now()), false)
+}
+
+/// Like [`timezones`], but the offsets reflect the date typed into the other options of
+/// `/epoch` (if any) so that daylight saving time on that date is accounted for. The
+/// queries are observed for learning the popularity of the eventually submitted zone.
+fn epoch_timezones(context: &Context) -> Vec<CommandOptionChoice> {
+    let at = context.datetime().map_or_else(|| At::Now(context.state.clock.now()), At::Typed);
+    timezones_at(context, at, true)
+}
+
+/// The invoker's favorite and recent zones come first for short queries whereas popular
+/// zones rank higher in general. Zones which matched by an alias (e.g., a country or a
+/// city) mention it in their labels along with the offset of the zone `at` the given
+/// instant.
+fn timezones_at(context: &Context, at: At, learn: bool) -> Vec<CommandOptionChoice> {
+    let State { store, clock, learner, .. } = context.state;
+    let (user, now) = (context.interaction.author_id(), clock.now());
+    let ((favorites, recent, no_history), boosts) = store.read(|db| {
+        let preferences = db.preferences.iter().find(|preferences| Some(preferences.user) == user);
+        let preferences = preferences.map(|preferences| {
+            (preferences.favorites.clone(), preferences.recent.clone(), preferences.no_history)
+        });
+        (preferences.unwrap_or_default(), db.popularity.boosts(context.query, now, &learner.prior))
+    });
+    if learn
+        && !no_history
+        && let Some(user) = user
+    {
+        learner.observe(user.get(), context.query, now);
+    }
+
+    let search = crate::util::Search {
+        count: MAX_CHOICES,
+        scorer: &crate::util::score::Segmented::DEFAULT,
+        personal: crate::util::Personalization { favorites: &favorites, recent: &recent },
+        boosts: Some(&boosts),
+    };
+    crate::util::autocomplete_tz(context.query, search)
+        .into_iter()
+        .map(|found| {
+            let suffix = describe(found.zone, at).map(|text| format!(" — {text}"));
+            let label = |label: String| {
+                let label = match found.alias {
+                    Some(alias) => format!("{label} ({alias})"),
+                    None => label,
+                };
+                match &suffix {
+                    Some(suffix) => labelled(label, suffix),
+                    None => label,
+                }
+            };
+            CommandOptionChoice {
+                name: label(i18n::EN.regions.label(found.zone)),
+                name_localizations: i18n::zone_localizations(found.zone).map(|localizations| {
+                    localizations.into_iter().map(|(locale, name)| (locale, label(name))).collect()
+                }),
+                value: CommandOptionChoiceValue::String(String::from(found.zone)),
+            }
+        })
+        .collect()
+}
+
+/// Describes the offset of the `zone` (e.g., `UTC+08:00 (PST) · 14:32`) at the instant.
+fn describe(zone: &str, at: At) -> Option<String> {
+    let tz = jiff::tz::TimeZone::get(zone).ok()?;
+    Some(match at {
+        At::Now(now) => {
+            let time = now.to_zoned(tz.clone()).strftime("%H:%M");
+            format!("{} · {time}", crate::util::describe_offset(&tz, now))
+        }
+        At::Typed(datetime) => {
+            let zoned = datetime.to_zoned(tz.clone()).ok()?;
+            let date = datetime.date();
+            format!("{} · {date}", crate::util::describe_offset(&tz, zoned.timestamp()))
+        }
+    })
+}
+
+/// Appends the `suffix` to the `label`, which is truncated instead of the suffix so that
+/// the whole stays within [`MAX_LENGTH`] characters.
+fn labelled(label: String, suffix: &str) -> String {
+    let max = MAX_LENGTH.saturating_sub(suffix.chars().count());
+    truncate_to(label, max) + suffix
+}
+
+/// Years (nearest to the current one first) whose digits start with the query.
+fn years(context: &Context) -> Vec<CommandOptionChoice> {
+    let query = context.query.trim();
+    let current = context.today().year();
+    let nearby =
+        (0..=100).flat_map(|delta| [current.saturating_add(delta), current.saturating_sub(delta)]);
+    let mut years: Vec<_> = nearby.filter(|year| year.to_string().starts_with(query)).collect();
+    years.dedup();
+
+    // Distant years are only suggested when typed in full.
+    if let Ok(year) = query.parse::<i16>()
+        && !years.contains(&year)
+    {
+        years.insert(0, year);
+    }
+
+    years.into_iter().map(|year| integer(year.to_string(), year.into())).collect()
+}
+
+/// Days of the chosen month (January by default) along with their weekdays in the chosen
+/// year (the current year by default).
+fn days(context: &Context) -> Vec<CommandOptionChoice> {
+    let year = context.integer("year").and_then(|year| i16::try_from(year).ok());
+    let month = context.integer("month").and_then(|month| i8::try_from(month).ok());
+    let year = year.unwrap_or_else(|| context.today().year());
+    days_of(year, month.unwrap_or(1), context.query.trim(), context.catalog)
+}
+
+fn days_of(year: i16, month: i8, query: &str, catalog: &Catalog) -> Vec<CommandOptionChoice> {
+    let Ok(first) = jiff::civil::Date::new(year, month, 1) else {
+        return Vec::new();
+    };
+    (1..=first.days_in_month())
+        .filter(|day| day.to_string().starts_with(query))
+        .map(|day| {
+            let date = jiff::civil::date(year, month, day);
+            let weekday =
+                catalog.calendar.weekdays[date.weekday().to_monday_zero_offset() as usize];
+            integer(format!("{day} ({weekday})"), day.into())
+        })
+        .collect()
+}
+
+/// Creates an integer choice.
+fn integer(name: String, value: i64) -> CommandOptionChoice {
+    CommandOptionChoice {
+        name,
+        name_localizations: None,
+        value: CommandOptionChoiceValue::Integer(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::application::command::{
+        CommandOption, CommandOptionChoice, CommandOptionChoiceValue,
+    };
+
+    #[test]
+    fn every_autocompletion_has_provider() {
+        fn collect(path: &str, options: &[CommandOption], pairs: &mut Vec<(String, String)>) {
+            for option in options {
+                match option.options.as_deref() {
+                    Some(inner) => collect(&format!("{path} {}", option.name), inner, pairs),
+                    None if option.autocomplete == Some(true) => {
+                        pairs.push((String::from(path), option.name.clone()));
+                    }
+                    None => (),
+                }
+            }
+        }
+
+        let mut pairs = Vec::new();
+        for command in super::super::definitions() {
+            collect(&command.name, &command.options, &mut pairs);
+        }
+        let mut provided: Vec<_> = super::PROVIDERS
+            .iter()
+            .map(|(path, option, _)| (String::from(*path), String::from(*option)))
+            .collect();
+        pairs.sort_unstable();
+        provided.sort_unstable();
+        assert_eq!(pairs, provided);
+    }
+
+    #[test]
+    fn choices_within_limits() {
+        let choice = |name: String, value: String| CommandOptionChoice {
+            name,
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(value),
+        };
+        let mut choices: Vec<_> =
+            (0..30).map(|i| choice("é".repeat(90 + i), i.to_string())).collect();
+        choices.insert(0, choice(String::from("Too long"), "x".repeat(101)));
+
+        let choices = super::limit(choices);
+        assert_eq!(choices.len(), 25);
+        assert_eq!(choices[0].value, CommandOptionChoiceValue::String(String::from("0")));
+        assert!(choices.iter().all(|choice| choice.name.chars().count() <= 100));
+        assert!(choices[24].name.ends_with('…'));
+    }
+
+    #[test]
+    fn labels_with_offsets() {
+        use super::{At, describe};
+        let now: jiff::Timestamp = "2024-01-15T06:32:00Z".parse().unwrap();
+        assert_eq!(describe("Asia/Manila", At::Now(now)).unwrap(), "UTC+08:00 (PST) · 14:32");
+        assert_eq!(describe("Asia/Dubai", At::Now(now)).unwrap(), "UTC+04:00 · 10:32");
+        assert!(describe("Mars/Olympus", At::Now(now)).is_none());
+
+        // Offsets follow daylight saving time on the typed date.
+        let summer = At::Typed(jiff::civil::datetime(2024, 7, 1, 12, 0, 0, 0));
+        let winter = At::Typed(jiff::civil::datetime(2024, 1, 1, 12, 0, 0, 0));
+        assert_eq!(describe("Europe/Berlin", summer).unwrap(), "UTC+02:00 (CEST) · 2024-07-01");
+        assert_eq!(describe("Europe/Berlin", winter).unwrap(), "UTC+01:00 (CET) · 2024-01-01");
+
+        let label = super::labelled("x".repeat(120), " — UTC+08:00 (PST) · 14:32");
+        assert_eq!(label.chars().count(), 100);
+        assert!(label.ends_with("… — UTC+08:00 (PST) · 14:32"));
+    }
+
+    #[test]
+    fn days_of_month() {
+        let days = super::days_of(2024, 2, "2", &crate::i18n::EN);
+        let names: Vec<_> = days.iter().map(|choice| choice.name.as_str()).collect();
+        assert_eq!(names[..2], ["2 (Friday)", "20 (Tuesday)"]);
+        assert_eq!(names.last(), Some(&"29 (Thursday)"));
+        assert_eq!(super::days_of(2023, 2, "29", &crate::i18n::EN).len(), 0);
+        assert_eq!(super::days_of(2023, 13, "", &crate::i18n::EN).len(), 0);
+    }
+}
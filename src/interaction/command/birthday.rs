@@ -0,0 +1,167 @@
+use super::{args, error, schema};
+use crate::{
+    State,
+    store::{Birthday, LeapFallback},
+    util::tz::Zone,
+};
+use twilight_model::{
+    application::{command::Command, interaction::Interaction},
+    channel::message::MessageFlags,
+    http::interaction::InteractionResponseData,
+    id::{
+        Id,
+        marker::{ChannelMarker, UserMarker},
+    },
+};
+
+/// Parses a `MM-DD` string. Note that February 29 is always accepted.
+fn parse_date(text: &str) -> Option<(i8, i8)> {
+    let (month, day) = text.trim().split_once('-')?;
+    let (month, day) = (month.parse().ok()?, day.parse().ok()?);
+    // Validate against a leap year so that February 29 is accepted.
+    jiff::civil::Date::new(2000, month, day).ok()?;
+    Some((month, day))
+}
+
+args::choices!(LeapFallback { "feb-28" => LeapFallback::Feb28, "mar-1" => LeapFallback::Mar1 });
+
+args::command_args! {
+    /// Arguments of the `/birthday set` subcommand.
+    pub struct SetArgs {
+        date: String = "date",
+        timezone: Zone = "timezone" => Zone::utc(),
+        fallback: LeapFallback = "fallback" => LeapFallback::default(),
+    }
+}
+
+/// Resolves the channel and the user who invoked the command.
+fn invoker(interaction: &Interaction) -> error::Result<(Id<ChannelMarker>, Id<UserMarker>)> {
+    let channel = interaction.channel.as_ref().ok_or(error::Error::MissingChannel)?.id;
+    let user = interaction.author_id().ok_or(error::Error::MissingPayload)?;
+    Ok((channel, user))
+}
+
+/// Wraps the `content` as a private reply.
+fn reply(content: String) -> InteractionResponseData {
+    InteractionResponseData {
+        content: Some(content),
+        flags: Some(MessageFlags::EPHEMERAL),
+        ..Default::default()
+    }
+}
+
+/// Handler for the `/birthday set` subcommand.
+pub fn set(
+    interaction: &Interaction,
+    SetArgs { date, timezone: Zone { name: zone, tz }, fallback }: SetArgs,
+    state: &State,
+) -> error::Result<InteractionResponseData> {
+    let (channel, user) = invoker(interaction)?;
+    let (month, day) = parse_date(&date).ok_or(error::Error::InvalidDate)?;
+    // Avoid announcing twice if the birthday is updated on the day itself.
+    let today = state.clock.now().to_zoned(tz).date();
+    let mut birthday = Birthday { user, channel, month, day, zone, fallback, announced: None };
+    if birthday.occurrence(today.year()) == today {
+        birthday.announced = Some(today.year());
+    }
+
+    state
+        .store
+        .write(|db| {
+            db.birthdays.retain(|other| other.user != user || other.channel != channel);
+            db.birthdays.push(birthday.clone());
+        })
+        .map_err(|err| {
+            log::error!("Failed to persist birthday: {err}.");
+            error::Error::Storage
+        })?;
+
+    super::zones::remember(interaction, state, &birthday.zone);
+    Ok(reply(format!(
+        "Your birthday ({month:02}-{day:02}) will be announced in this channel at midnight in **{}**.",
+        birthday.zone
+    )))
+}
+
+/// Handler for the `/birthday unset` subcommand.
+pub fn unset(interaction: &Interaction, state: &State) -> error::Result<InteractionResponseData> {
+    let (channel, user) = invoker(interaction)?;
+    let removed = state
+        .store
+        .write(|db| {
+            let before = db.birthdays.len();
+            db.birthdays.retain(|other| other.user != user || other.channel != channel);
+            before - db.birthdays.len()
+        })
+        .map_err(|err| {
+            log::error!("Failed to persist birthday removal: {err}.");
+            error::Error::Storage
+        })?;
+
+    Ok(reply(String::from(if removed == 0 {
+        "You have no birthday registered in this channel."
+    } else {
+        "Your birthday will no longer be announced in this channel."
+    })))
+}
+
+/// Registered definition of the `/birthday` command.
+pub fn schema() -> Command {
+    use schema::{autocomplete, choice, option, required, subcommand};
+    use twilight_model::application::command::{
+        CommandOption, CommandOptionChoiceValue, CommandOptionType::String,
+    };
+
+    let fallbacks = [("feb-28", 1, "28"), ("mar-1", 2, "1")]
+        .into_iter()
+        .map(|(value, month, day)| {
+            choice(
+                move |catalog| {
+                    let calendar = &catalog.calendar;
+                    crate::i18n::fill(
+                        calendar.day_month,
+                        &[("day", day), ("month", calendar.months[month])],
+                    )
+                },
+                CommandOptionChoiceValue::String(value.into()),
+            )
+        })
+        .collect();
+
+    schema::guild_only(schema::command(
+        "birthday",
+        |c| c.birthday,
+        Vec::from([
+            subcommand(
+                "set",
+                |c| c.birthday_set,
+                Vec::from([
+                    required(CommandOption {
+                        min_length: Some(3),
+                        max_length: Some(5),
+                        ..option(String, "date", |c| c.birthday_date)
+                    }),
+                    autocomplete(option(String, "timezone", |c| c.birthday_timezone)),
+                    CommandOption {
+                        choices: Some(fallbacks),
+                        ..option(String, "fallback", |c| c.birthday_fallback)
+                    },
+                ]),
+            ),
+            subcommand("unset", |c| c.birthday_unset, Vec::new()),
+        ]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parse_dates() {
+        assert_eq!(super::parse_date("02-29"), Some((2, 29)));
+        assert_eq!(super::parse_date("12-31"), Some((12, 31)));
+        assert_eq!(super::parse_date("4-1"), Some((4, 1)));
+        assert_eq!(super::parse_date("02-30"), None);
+        assert_eq!(super::parse_date("13-01"), None);
+        assert_eq!(super::parse_date("0229"), None);
+    }
+}
@@ -0,0 +1,80 @@
+use super::{args, error, schema};
+use crate::{State, job::countdown, store::Countdown, util::tz::Zone};
+use twilight_model::{
+    application::{command::Command, interaction::Interaction},
+    http::interaction::InteractionResponseData,
+};
+
+/// Registered definition of the `/countdown` command.
+pub fn schema() -> Command {
+    use schema::{autocomplete, option, required};
+    use twilight_model::application::command::{CommandOption, CommandOptionType::String};
+    schema::guild_only(schema::command(
+        "countdown",
+        |c| c.countdown,
+        Vec::from([
+            required(option(String, "to", |c| c.countdown_to)),
+            required(autocomplete(option(String, "timezone", |c| c.countdown_timezone))),
+            CommandOption {
+                max_length: Some(200),
+                ..option(String, "title", |c| c.countdown_title)
+            },
+        ]),
+    ))
+}
+
+args::command_args! {
+    /// Arguments of the `/countdown` command.
+    pub struct Args {
+        to: String = "to",
+        timezone: Zone = "timezone",
+        title: Option<String> = "title",
+    }
+}
+
+/// Handler for the `/countdown` command.
+pub fn execute(
+    interaction: &Interaction,
+    Args { to, timezone, title }: Args,
+    state: &State,
+) -> error::Result<InteractionResponseData> {
+    let to = to.trim().parse::<jiff::civil::DateTime>().map_err(|err| {
+        log::error!("Failed to parse target date and time: {err}.");
+        error::Error::InvalidDateTime
+    })?;
+    let title = title.map(String::into_boxed_str);
+    let target = to
+        .to_zoned(timezone.tz)
+        .map_err(|err| {
+            log::error!("Timezone conversion failed: {err}.");
+            error::Error::InvalidArgs
+        })?
+        .timestamp();
+
+    let now = state.clock.now();
+    if target <= now {
+        return Err(error::Error::InPast);
+    }
+
+    let content = countdown::render(title.as_deref(), target, now);
+    state
+        .store
+        .write(|db| {
+            db.countdowns.push(Countdown {
+                application: interaction.application_id,
+                token: interaction.token.as_str().into(),
+                created: now,
+                target,
+                title,
+                channel: None,
+                message: None,
+            })
+        })
+        .map_err(|err| {
+            log::error!("Failed to persist countdown: {err}.");
+            error::Error::Storage
+        })?;
+
+    super::zones::remember(interaction, state, &timezone.name);
+    Ok(InteractionResponseData { content: Some(content), ..Default::default() })
+}
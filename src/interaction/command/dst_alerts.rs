@@ -0,0 +1,180 @@
+use super::{args, autocomplete, error, schema};
+use crate::{
+    State,
+    job::dst,
+    store::DstAlert,
+    util::{
+        score,
+        tz::{self, Zone},
+    },
+};
+use twilight_model::{
+    application::{
+        command::{Command, CommandOptionChoice, CommandOptionChoiceValue},
+        interaction::Interaction,
+    },
+    channel::message::MessageFlags,
+    http::interaction::InteractionResponseData,
+};
+
+args::command_args! {
+    /// Arguments of the `/dst-alerts subscribe` subcommand.
+    pub struct SubscribeArgs {
+        zone: Zone = "zone",
+        lead: u16 = "lead",
+    }
+}
+
+args::command_args! {
+    /// Arguments of the `/dst-alerts unsubscribe` subcommand. The zone is kept as text so
+    /// that subscriptions to zones since removed from the database may still be removed.
+    pub struct UnsubscribeArgs {
+        zone: String = "zone",
+    }
+}
+
+/// Wraps the `content` as a private reply.
+fn reply(content: String) -> InteractionResponseData {
+    InteractionResponseData {
+        content: Some(content),
+        flags: Some(MessageFlags::EPHEMERAL),
+        ..Default::default()
+    }
+}
+
+/// Handler for the `/dst-alerts subscribe` subcommand.
+pub fn subscribe(
+    interaction: &Interaction,
+    SubscribeArgs { zone: Zone { name: zone, tz }, lead }: SubscribeArgs,
+    state: &State,
+) -> error::Result<InteractionResponseData> {
+    let channel = interaction.channel.as_ref().ok_or(error::Error::MissingChannel)?.id;
+    state
+        .store
+        .write(|db| {
+            match db
+                .dst_alerts
+                .iter_mut()
+                .find(|alert| alert.channel == channel && alert.zone == zone)
+            {
+                Some(alert) => alert.lead = lead,
+                None => db.dst_alerts.push(DstAlert {
+                    channel,
+                    zone: zone.clone(),
+                    lead,
+                    notified: None,
+                }),
+            }
+        })
+        .map_err(|err| {
+            log::error!("Failed to persist DST alert subscription: {err}.");
+            error::Error::Storage
+        })?;
+
+    super::zones::remember(interaction, state, &zone);
+    let now = state.clock.now();
+    Ok(reply(match dst::next_transition(&tz, now) {
+        Some(at) => format!(
+            "This channel will be alerted {lead} day(s) before each transition in **{zone}**. The next one is on <t:{}:F>.",
+            at.as_second()
+        ),
+        None => format!(
+            "This channel is now subscribed to **{zone}**, but it has no upcoming transitions."
+        ),
+    }))
+}
+
+/// Handler for the `/dst-alerts unsubscribe` subcommand.
+pub fn unsubscribe(
+    interaction: &Interaction,
+    UnsubscribeArgs { zone }: UnsubscribeArgs,
+    state: &State,
+) -> error::Result<InteractionResponseData> {
+    let channel = interaction.channel.as_ref().ok_or(error::Error::MissingChannel)?.id;
+    // Normalize the name so that it matches the one persisted upon subscription.
+    let zone = match tz::resolve(&zone) {
+        Ok(resolved) => String::from(resolved.name),
+        Err(_) => zone,
+    };
+
+    let removed = state
+        .store
+        .write(|db| {
+            let before = db.dst_alerts.len();
+            db.dst_alerts.retain(|alert| alert.channel != channel || *alert.zone != *zone);
+            before - db.dst_alerts.len()
+        })
+        .map_err(|err| {
+            log::error!("Failed to persist DST alert removal: {err}.");
+            error::Error::Storage
+        })?;
+
+    Ok(reply(if removed == 0 {
+        format!("This channel is not subscribed to **{zone}**.")
+    } else {
+        format!("This channel will no longer receive alerts for **{zone}**.")
+    }))
+}
+
+/// Autocompletes the zones to which the channel is subscribed.
+pub fn subscriptions(context: &autocomplete::Context) -> Vec<CommandOptionChoice> {
+    let Some(channel) = context.interaction.channel.as_ref().map(|channel| channel.id) else {
+        return Vec::new();
+    };
+    context.state.store.read(|db| {
+        let alerts = db.dst_alerts.iter().filter(|alert| alert.channel == channel);
+        let scorer = &score::Subsequence;
+        score::rank(alerts, |alert| &alert.zone, context.query, scorer, autocomplete::MAX_CHOICES)
+            .into_iter()
+            .map(|alert| CommandOptionChoice {
+                name: alert.zone.replace('_', " "),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String(String::from(&*alert.zone)),
+            })
+            .collect()
+    })
+}
+
+/// Registered definition of the `/dst-alerts` command, which requires the permission
+/// to manage channels by default.
+pub fn schema() -> Command {
+    use schema::{autocomplete, option, required, subcommand};
+    use twilight_model::{
+        application::command::{
+            CommandOption,
+            CommandOptionType::{Integer, String},
+            CommandOptionValue,
+        },
+        guild::Permissions,
+    };
+
+    let command = schema::command(
+        "dst-alerts",
+        |c| c.dst_alerts,
+        Vec::from([
+            subcommand(
+                "subscribe",
+                |c| c.dst_subscribe,
+                Vec::from([
+                    required(autocomplete(option(String, "zone", |c| c.dst_subscribe_zone))),
+                    required(CommandOption {
+                        min_value: Some(CommandOptionValue::Integer(1)),
+                        max_value: Some(CommandOptionValue::Integer(30)),
+                        ..option(Integer, "lead", |c| c.dst_lead)
+                    }),
+                ]),
+            ),
+            subcommand(
+                "unsubscribe",
+                |c| c.dst_unsubscribe,
+                Vec::from([required(autocomplete(option(String, "zone", |c| {
+                    c.dst_unsubscribe_zone
+                })))]),
+            ),
+        ]),
+    );
+    Command {
+        default_member_permissions: Some(Permissions::MANAGE_CHANNELS),
+        ..schema::guild_only(command)
+    }
+}
@@ -1,177 +1,579 @@
-use super::error;
+use super::{args, browse, custom_id, error, schema, share};
+use crate::{
+    i18n::{self, Catalog},
+    util::tz::{self, Zone},
+};
 use twilight_model::{
-    application::interaction::application_command::CommandData,
-    http::interaction::InteractionResponseData,
+    application::command::Command,
+    channel::message::{Component, MessageFlags},
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
 
-/// Handler for the `/epoch` command.
-pub fn execute(data: CommandData) -> error::Result<InteractionResponseData> {
-    use twilight_model::{
-        application::interaction::application_command::{CommandDataOption, CommandOptionValue},
-        channel::message::{
-            MessageFlags,
-            embed::{Embed, EmbedField},
-        },
+/// The supported `<t:…:X>` styles in display order. Their human-readable names are
+/// given (in the same order) by the catalog.
+const STYLES: [char; 7] = ['t', 'T', 'd', 'D', 'f', 'F', 'R'];
+
+// Option names shared by the schema and the parser.
+const TIMEZONE: &str = "timezone";
+const YEAR: &str = "year";
+const MONTH: &str = "month";
+const DAY: &str = "day";
+const HOUR: &str = "hour";
+const MINUTE: &str = "minute";
+const SECOND: &str = "second";
+const PREVIEW: &str = "preview";
+
+/// Style selected by default in new previews.
+const DEFAULT_STYLE: char = 'f';
+
+/// Zones offered for quick re-rendering in the preview. This must not exceed the
+/// 25-option limit of select menus.
+const ZONES: [&str; 20] = [
+    "UTC",
+    "America/Los_Angeles",
+    "America/Denver",
+    "America/Chicago",
+    "America/New_York",
+    "America/Sao_Paulo",
+    "Europe/London",
+    "Europe/Paris",
+    "Europe/Berlin",
+    "Europe/Moscow",
+    "Africa/Cairo",
+    "Africa/Lagos",
+    "Asia/Dubai",
+    "Asia/Kolkata",
+    "Asia/Bangkok",
+    "Asia/Manila",
+    "Asia/Shanghai",
+    "Asia/Tokyo",
+    "Australia/Sydney",
+    "Pacific/Auckland",
+];
+
+/// Registered definition of the `/epoch` command.
+pub fn schema() -> Command {
+    use schema::{Description, autocomplete, choice, command, option};
+    use twilight_model::application::command::{
+        CommandOption, CommandOptionChoiceValue, CommandOptionType, CommandOptionValue::Integer,
     };
 
-    // Set default epoch arguments
-    let mut tz = None;
-    let mut year = None;
-    let mut month = 1;
-    let mut day = 1;
-    let mut hour = 0;
-    let mut minute = 0;
-    let mut second = 0;
-    let mut preview = true;
-
-    // Parse each argument
-    for CommandDataOption { name, value } in data.options {
-        log::info!("Received argument [{name}] as {value:?}.");
-        let target = match name.as_str() {
-            "preview" => {
-                if let CommandOptionValue::Boolean(value) = value {
-                    preview = value;
-                } else {
-                    log::error!("Incorrect command option value received.");
-                    return Err(error::Error::Fatal);
-                }
-                continue;
-            }
-            "timezone" => {
-                let CommandOptionValue::String(text) = value else {
-                    log::error!("Non-string command option value encountered for timezone.");
-                    return Err(error::Error::Fatal);
-                };
-                tz = Some(match jiff::tz::TimeZone::get(text.as_str()) {
-                    Ok(timezone) => timezone,
-                    Err(err) => {
-                        log::error!("Failed to set timezone: {err}.");
-                        return Err(error::Error::UnknownTimezone);
-                    }
-                });
-                continue;
-            }
-            "year" => {
-                let CommandOptionValue::Integer(num) = value else {
-                    log::error!("Non-integer command option value encountered for year.");
-                    return Err(error::Error::Fatal);
-                };
-                year = Some(match i16::try_from(num) {
-                    Ok(val) => val,
-                    Err(err) => {
-                        log::error!("Integer argument is out of range: {err}.");
-                        return Err(error::Error::OutOfRange);
-                    }
-                });
-                continue;
-            }
-            "month" => &mut month,
-            "day" => &mut day,
-            "hour" => &mut hour,
-            "minute" => &mut minute,
-            "second" => &mut second,
-            other => unimplemented!("Unable to parse command name {other}."),
-        };
+    let integer = |name, description: Description, min, max| CommandOption {
+        min_value: Some(Integer(min)),
+        max_value: Some(Integer(max)),
+        ..option(CommandOptionType::Integer, name, description)
+    };
+    let months = (1..=12)
+        .map(|month| {
+            choice(
+                |catalog| schema::capitalize(catalog.calendar.months[month - 1]),
+                CommandOptionChoiceValue::Integer(month as i64),
+            )
+        })
+        .collect();
 
-        let CommandOptionValue::Integer(num) = value else {
-            log::error!("Non-integer command option value encountered for year.");
-            return Err(error::Error::Fatal);
-        };
+    command(
+        "epoch",
+        |c| c.epoch,
+        Vec::from([
+            autocomplete(option(CommandOptionType::String, TIMEZONE, |c| c.epoch_timezone)),
+            autocomplete(option(CommandOptionType::Integer, YEAR, |c| c.epoch_year)),
+            CommandOption { choices: Some(months), ..integer(MONTH, |c| c.epoch_month, 1, 12) },
+            autocomplete(integer(DAY, |c| c.epoch_day, 1, 31)),
+            integer(HOUR, |c| c.epoch_hour, 0, 23),
+            integer(MINUTE, |c| c.epoch_minute, 0, 59),
+            integer(SECOND, |c| c.epoch_second, 0, 60),
+            option(CommandOptionType::Boolean, PREVIEW, |c| c.epoch_preview),
+        ]),
+    )
+}
 
-        *target = match i8::try_from(num) {
-            Ok(val) => val,
-            Err(err) => {
-                log::error!("Unsigned integer argument is out of range: {err}.");
-                return Err(error::Error::OutOfRange);
-            }
-        };
+args::command_args! {
+    /// Arguments of the `/epoch` command.
+    pub struct Args {
+        timezone: Option<Zone> = TIMEZONE,
+        year: Option<i16> = YEAR,
+        month: Option<i8> = MONTH,
+        day: Option<i8> = DAY,
+        hour: Option<i8> = HOUR,
+        minute: Option<i8> = MINUTE,
+        second: Option<i8> = SECOND,
+        preview: bool = PREVIEW => true,
     }
+}
 
-    let (tz, year) = tz.zip(year).ok_or(error::Error::MissingRequired)?;
-    let timestamp =
-        match jiff::civil::datetime(year, month, day, hour, minute, second, 0).to_zoned(tz) {
-            Ok(zoned) => zoned.timestamp().as_second(),
-            Err(err) => {
-                log::error!("Timezone conversion failed: {err}.");
-                return Err(error::Error::InvalidArgs);
-            }
-        };
+impl Args {
+    /// Canonical name of the chosen timezone (if any).
+    pub fn zone(&self) -> Option<&str> {
+        self.timezone.as_ref().map(|zone| &*zone.name)
+    }
+}
 
+/// Handler for the `/epoch` command. If no date options are given, the user is instead
+/// prompted with a modal for entering the date and time as text.
+pub fn execute(args: Args, catalog: &Catalog) -> error::Result<InteractionResponse> {
+    let Args { timezone, year, month, day, hour, minute, second, preview } = args;
+    let has_date = year.is_some() || [month, day, hour, minute, second].iter().any(Option::is_some);
+    if !has_date {
+        return Ok(InteractionResponse {
+            kind: InteractionResponseType::Modal,
+            data: Some(modal(timezone.as_ref(), preview, catalog)?),
+        });
+    }
+
+    // Any date option requires both the timezone and the year
+    let Zone { name: zone, tz } =
+        timezone.ok_or_else(|| error::Error::MissingOption(TIMEZONE.into()))?;
+    let year = year.ok_or_else(|| error::Error::MissingOption(YEAR.into()))?;
+    let [month, day, hour, minute, second] = [
+        month.unwrap_or(1),
+        day.unwrap_or(1),
+        hour.unwrap_or(0),
+        minute.unwrap_or(0),
+        second.unwrap_or(0),
+    ];
+    let zoned = match jiff::civil::datetime(year, month, day, hour, minute, second, 0).to_zoned(tz)
+    {
+        Ok(zoned) => zoned,
+        Err(err) => {
+            log::error!("Timezone conversion failed: {err}.");
+            return Err(error::Error::InvalidArgs);
+        }
+    };
+
+    Ok(InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(respond(&zoned, &zone, preview, catalog)?),
+    })
+}
+
+/// Responds with either the full preview or just the raw timestamp.
+fn respond(
+    zoned: &jiff::Zoned,
+    zone: &str,
+    preview: bool,
+    catalog: &Catalog,
+) -> error::Result<InteractionResponseData> {
     Ok(if preview {
         InteractionResponseData {
-            embeds: Some(Vec::from([Embed {
-                title: Some(String::from("Timestamp Preview")),
-                description: Some(String::from(
-                    "Here are the possible ways to format your timestamp.",
-                )),
-                fields: Vec::from([
-                    {
-                        let format = format!("<t:{timestamp}:t>");
-                        EmbedField {
-                            inline: false,
-                            name: format!("Short Time ({format})"),
-                            value: format!("```{format}```"),
-                        }
-                    },
-                    {
-                        let format = format!("<t:{timestamp}:T>");
-                        EmbedField {
-                            inline: false,
-                            name: format!("Long Time ({format})"),
-                            value: format!("```{format}```"),
-                        }
-                    },
-                    {
-                        let format = format!("<t:{timestamp}:d>");
-                        EmbedField {
-                            inline: false,
-                            name: format!("Short Date ({format})"),
-                            value: format!("```{format}```"),
-                        }
-                    },
-                    {
-                        let format = format!("<t:{timestamp}:D>");
-                        EmbedField {
-                            inline: false,
-                            name: format!("Long Date ({format})"),
-                            value: format!("```{format}```"),
-                        }
-                    },
-                    {
-                        let format = format!("<t:{timestamp}:f>");
-                        EmbedField {
-                            inline: false,
-                            name: format!("Short Full Date + Time ({format})"),
-                            value: format!("```{format}```"),
-                        }
-                    },
-                    {
-                        let format = format!("<t:{timestamp}:F>");
-                        EmbedField {
-                            inline: false,
-                            name: format!("Long Full Date + Time ({format})"),
-                            value: format!("```{format}```"),
-                        }
-                    },
-                    {
-                        let format = format!("<t:{timestamp}:R>");
-                        EmbedField {
-                            inline: false,
-                            name: format!("Relative ({format})"),
-                            value: format!("```{format}```"),
-                        }
-                    },
-                ]),
-                ..super::embed::base()
-            }])),
             flags: Some(MessageFlags::EPHEMERAL),
-            ..Default::default()
+            ..render(zoned, zone, DEFAULT_STYLE, catalog)?
         }
     } else {
         InteractionResponseData {
-            content: Some(timestamp.to_string()),
+            content: Some(zoned.timestamp().as_second().to_string()),
             flags: Some(MessageFlags::EPHEMERAL),
             ..Default::default()
         }
     })
 }
+
+/// Builds the modal for entering the date, time, and timezone as text. The options
+/// already given to the command are round-tripped through the modal's `custom_id`. The
+/// given timezone also prefills its input, which may then be left empty.
+fn modal(
+    zone: Option<&Zone>,
+    preview: bool,
+    catalog: &Catalog,
+) -> error::Result<InteractionResponseData> {
+    use twilight_model::channel::message::component::{Label, TextInput, TextInputStyle};
+
+    let zone = zone.map(|zone| &*zone.name);
+    let custom_id = encode("epoch-modal", &[if preview { "1" } else { "0" }, zone.unwrap_or("")])?;
+    let input = |custom_id: &str, text: &i18n::Input, placeholder: &str, value: Option<&str>| {
+        #[expect(deprecated, reason = "the label is set through the wrapping component instead")]
+        let input = TextInput {
+            id: None,
+            custom_id: String::from(custom_id),
+            label: None,
+            max_length: Some(64),
+            min_length: None,
+            placeholder: Some(String::from(placeholder)),
+            required: Some(match custom_id {
+                "time" => false,
+                "timezone" => zone.is_none(),
+                _ => true,
+            }),
+            style: TextInputStyle::Short,
+            value: value.map(String::from),
+        };
+        Component::Label(Label {
+            id: None,
+            label: String::from(text.label),
+            description: Some(String::from(text.description)),
+            component: Box::new(Component::TextInput(input)),
+        })
+    };
+
+    Ok(InteractionResponseData {
+        custom_id: Some(custom_id),
+        title: Some(String::from(catalog.epoch.title)),
+        components: Some(Vec::from([
+            input("date", &catalog.epoch.date, "2030-01-31", None),
+            input("time", &catalog.epoch.time, "13:30", None),
+            input("timezone", &catalog.epoch.timezone, "Asia/Manila", zone),
+        ])),
+        ..Default::default()
+    })
+}
+
+/// Handler for the submission of the `/epoch` modal. The `fields` are those decoded from
+/// the `custom_id` (excluding the kind) whereas the `inputs` are pairs of each text
+/// input's `custom_id` and its submitted value. The timezone given to the command (if any)
+/// is the fallback for an empty input. Returns the canonical name of the chosen timezone
+/// along with the response.
+pub fn on_modal(
+    fields: &[&str],
+    inputs: &[(String, String)],
+    catalog: &Catalog,
+) -> error::Result<(InteractionResponseData, Box<str>)> {
+    let [preview, given] = fields else {
+        log::error!("Unexpected /epoch modal fields {fields:?}.");
+        return Err(error::Error::InvalidComponent);
+    };
+
+    let input = |name: &str| {
+        inputs
+            .iter()
+            .find_map(|(id, value)| (id == name).then_some(value.trim()))
+            .filter(|value| !value.is_empty())
+    };
+
+    let date: jiff::civil::Date = input("date")
+        .ok_or(error::Error::MissingRequired)?
+        .parse()
+        .map_err(|_| error::Error::InvalidField(error::Field::Date))?;
+    let time = match input("time") {
+        Some(text) => text.parse().map_err(|_| error::Error::InvalidField(error::Field::Time))?,
+        None => jiff::civil::Time::midnight(),
+    };
+    let zone = input("timezone")
+        .or_else(|| Some(given.trim()).filter(|given| !given.is_empty()))
+        .ok_or(error::Error::MissingRequired)?;
+    let Zone { name: zone, tz } = tz::resolve(zone).map_err(|err| match err {
+        tz::Unresolved::Unknown => error::Error::InvalidField(error::Field::Timezone),
+        ambiguous => ambiguous.into(),
+    })?;
+
+    let zoned = date.to_datetime(time).to_zoned(tz).map_err(|err| {
+        log::error!("Timezone conversion failed: {err}.");
+        error::Error::OutOfRange
+    })?;
+    Ok((respond(&zoned, &zone, *preview != "0", catalog)?, zone))
+}
+
+fn encode(kind: &str, fields: &[&str]) -> error::Result<String> {
+    custom_id::encode(kind, fields).map_err(|err| {
+        log::error!("Failed to encode custom ID: {err:?}.");
+        error::Error::Fatal
+    })
+}
+
+/// Renders the preview embed of `zoned` along with its interactive components. The `zone`
+/// is the canonical name of its timezone (see [`tz::Zone`]), which is encoded into them.
+fn render(
+    zoned: &jiff::Zoned,
+    zone: &str,
+    style: char,
+    catalog: &Catalog,
+) -> error::Result<InteractionResponseData> {
+    use twilight_model::channel::message::embed::{Embed, EmbedField};
+
+    let timestamp = zoned.timestamp().as_second();
+    let index =
+        STYLES.iter().position(|other| *other == style).ok_or(error::Error::InvalidComponent)?;
+    let style_name = catalog.epoch.styles[index];
+    let selected = format!("<t:{timestamp}:{style}>");
+    let showing = i18n::fill(
+        catalog.epoch.showing,
+        &[
+            ("date", &catalog.calendar.format(zoned.date())),
+            ("time", &zoned.strftime("%H:%M:%S").to_string()),
+            ("zone", zone),
+            ("offset", &crate::util::format_offset(zoned.offset())),
+        ],
+    );
+    let description = format!(
+        "{}\n\n{showing}\n\n**{style_name}:** {selected}\n```{selected}```",
+        catalog.epoch.intro,
+    );
+
+    Ok(InteractionResponseData {
+        embeds: Some(Vec::from([Embed {
+            title: Some(String::from(catalog.epoch.title)),
+            description: Some(description),
+            fields: STYLES
+                .iter()
+                .zip(catalog.epoch.styles)
+                .map(|(style, name)| {
+                    let format = format!("<t:{timestamp}:{style}>");
+                    EmbedField {
+                        inline: false,
+                        name: format!("{name} ({format})"),
+                        value: format!("```{format}```"),
+                    }
+                })
+                .collect(),
+            ..super::embed::base()
+        }])),
+        components: Some(components(timestamp, zone, style, catalog)?),
+        ..Default::default()
+    })
+}
+
+fn components(
+    timestamp: i64,
+    zone: &str,
+    style: char,
+    catalog: &Catalog,
+) -> error::Result<Vec<Component>> {
+    use twilight_model::channel::message::component::{
+        ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption, SelectMenuType,
+    };
+
+    let share = share::button(timestamp, style, catalog)?;
+    let timestamp = timestamp.to_string();
+    let style = style.to_string();
+    let buttons = ["-P1D", "-PT1H", "PT1H", "P1D"]
+        .into_iter()
+        .zip(catalog.epoch.shifts)
+        .map(|(span, label)| {
+            Ok(Component::Button(Button {
+                id: None,
+                custom_id: Some(encode("epoch", &["shift", span, &timestamp, zone, &style])?),
+                disabled: false,
+                emoji: None,
+                label: Some(String::from(label)),
+                style: ButtonStyle::Secondary,
+                url: None,
+                sku_id: None,
+            }))
+        })
+        .chain([browse::start_button(&["epoch", "zone", &timestamp, zone, &style])])
+        .collect::<error::Result<_>>()?;
+
+    let styles = SelectMenu {
+        id: None,
+        channel_types: None,
+        custom_id: encode("epoch", &["style", &timestamp, zone])?,
+        default_values: None,
+        disabled: false,
+        kind: SelectMenuType::Text,
+        max_values: Some(1),
+        min_values: Some(1),
+        options: Some(
+            STYLES
+                .iter()
+                .zip(catalog.epoch.styles)
+                .map(|(other, name)| SelectMenuOption {
+                    default: other.to_string() == style,
+                    description: Some(format!("<t:…:{other}>")),
+                    emoji: None,
+                    label: String::from(name),
+                    value: other.to_string(),
+                })
+                .collect(),
+        ),
+        placeholder: Some(String::from(catalog.epoch.style_placeholder)),
+        required: None,
+    };
+
+    let zones = SelectMenu {
+        id: None,
+        channel_types: None,
+        custom_id: encode("epoch", &["zone", &timestamp, zone, &style])?,
+        default_values: None,
+        disabled: false,
+        kind: SelectMenuType::Text,
+        max_values: Some(1),
+        min_values: Some(1),
+        options: Some(
+            ZONES
+                .iter()
+                .map(|other| SelectMenuOption {
+                    default: *other == zone,
+                    description: None,
+                    emoji: None,
+                    label: other.replace('_', " "),
+                    value: String::from(*other),
+                })
+                .collect(),
+        ),
+        placeholder: Some(String::from(catalog.epoch.zone_placeholder)),
+        required: None,
+    };
+
+    Ok(Vec::from([
+        Component::ActionRow(ActionRow { id: None, components: buttons }),
+        Component::ActionRow(ActionRow {
+            id: None,
+            components: Vec::from([Component::SelectMenu(styles)]),
+        }),
+        Component::ActionRow(ActionRow {
+            id: None,
+            components: Vec::from([Component::SelectMenu(zones)]),
+        }),
+        Component::ActionRow(ActionRow { id: None, components: Vec::from([share]) }),
+    ]))
+}
+
+/// Handler for the components of the `/epoch` preview. The `fields` are those decoded
+/// from the `custom_id` (excluding the kind) whereas the `values` are the selected
+/// options of a select menu (if any).
+pub fn on_component(
+    fields: &[&str],
+    values: &[String],
+    catalog: &Catalog,
+) -> error::Result<InteractionResponseData> {
+    use jiff::{Timestamp, Zoned};
+
+    let parse = |timestamp: &str, zone: &str| -> error::Result<Zoned> {
+        let timestamp = timestamp.parse().map_err(|_| error::Error::InvalidComponent)?;
+        let timestamp = Timestamp::from_second(timestamp).map_err(|_| error::Error::OutOfRange)?;
+        Ok(timestamp.to_zoned(tz::resolve(zone)?.tz))
+    };
+    let parse_style = |style: &str| -> error::Result<char> {
+        let mut chars = style.chars();
+        match (chars.next(), chars.next()) {
+            (Some(style), None) => Ok(style),
+            _ => Err(error::Error::InvalidComponent),
+        }
+    };
+    let selected = || values.first().map(String::as_str).ok_or(error::Error::InvalidComponent);
+
+    match fields {
+        ["shift", span, timestamp, zone, style] => {
+            let span: jiff::Span = span.parse().map_err(|_| error::Error::InvalidComponent)?;
+            let zoned = parse(timestamp, zone)?.checked_add(span).map_err(|err| {
+                log::error!("Failed to shift timestamp: {err}.");
+                error::Error::OutOfRange
+            })?;
+            render(&zoned, zone, parse_style(style)?, catalog)
+        }
+        ["style", timestamp, zone] => {
+            render(&parse(timestamp, zone)?, zone, parse_style(selected()?)?, catalog)
+        }
+        ["zone", timestamp, zone, style] => {
+            // Keep the wall-clock time, but reinterpret it in the newly selected zone.
+            let Zone { name: selected, tz } = tz::resolve(selected()?)?;
+            let zoned = parse(timestamp, zone)?.datetime().to_zoned(tz).map_err(|err| {
+                log::error!("Timezone conversion failed: {err}.");
+                error::Error::InvalidArgs
+            })?;
+            render(&zoned, &selected, parse_style(style)?, catalog)
+        }
+        other => {
+            log::error!("Unexpected /epoch component fields {other:?}.");
+            Err(error::Error::InvalidComponent)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::custom_id;
+    use crate::i18n::EN;
+    use twilight_model::channel::message::Component;
+
+    /// Collects every `custom_id` in the rendered components.
+    fn custom_ids(components: &[Component]) -> Vec<String> {
+        components
+            .iter()
+            .flat_map(|row| match row {
+                Component::ActionRow(row) => row.components.as_slice(),
+                _ => &[],
+            })
+            .filter_map(|component| match component {
+                Component::Button(button) => button.custom_id.clone(),
+                Component::SelectMenu(menu) => Some(menu.custom_id.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn custom_ids_fit_for_every_zone() {
+        for zone in jiff::tz::db().available() {
+            let components = super::components(i64::MIN, zone.as_str(), 'R', &EN).unwrap();
+            assert_eq!(custom_ids(&components).len(), 8);
+        }
+    }
+
+    #[test]
+    fn modal_round_trip() {
+        let zone = crate::util::tz::resolve("Asia/Manila").unwrap();
+        let data = super::modal(Some(&zone), false, &EN).unwrap();
+        let custom_id = data.custom_id.unwrap();
+        let (kind, fields) = custom_id::decode(&custom_id).unwrap();
+        assert_eq!((kind, fields.as_slice()), ("epoch-modal", ["0", "Asia/Manila"].as_slice()));
+
+        let inputs = |date: &str, time: &str, zone: &str| {
+            Vec::from([
+                (String::from("date"), String::from(date)),
+                (String::from("time"), String::from(time)),
+                (String::from("timezone"), String::from(zone)),
+            ])
+        };
+        let (data, zone) =
+            super::on_modal(&fields, &inputs("2030-01-31", "13:30", "Asia/Manila"), &EN).unwrap();
+        assert_eq!((data.content.as_deref(), &*zone), (Some("1896067800"), "Asia/Manila"));
+        let (data, _) = super::on_modal(&["1", ""], &inputs("2030-01-31", "", "UTC"), &EN).unwrap();
+        assert!(data.embeds.is_some());
+
+        // The timezone given to the command fills in for an empty input.
+        let (data, zone) =
+            super::on_modal(&fields, &inputs("2030-01-31", "13:30", ""), &EN).unwrap();
+        assert_eq!((data.content.as_deref(), &*zone), (Some("1896067800"), "Asia/Manila"));
+        let err = super::on_modal(&["0", ""], &inputs("2030-01-31", "", ""), &EN).unwrap_err();
+        assert!(matches!(err, super::error::Error::MissingRequired));
+
+        let err = super::on_modal(&fields, &inputs("31/01/2030", "", "UTC"), &EN).unwrap_err();
+        assert!(matches!(err, super::error::Error::InvalidField(super::error::Field::Date)));
+        let err = super::on_modal(&fields, &inputs("2030-01-31", "1pm", "UTC"), &EN).unwrap_err();
+        assert!(matches!(err, super::error::Error::InvalidField(super::error::Field::Time)));
+        let err =
+            super::on_modal(&fields, &inputs("2030-01-31", "", "Mars/Olympus"), &EN).unwrap_err();
+        assert!(matches!(err, super::error::Error::InvalidField(super::error::Field::Timezone)));
+
+        // Offsets are round-tripped through the components by their canonical names.
+        let (data, _) =
+            super::on_modal(&["1", ""], &inputs("2030-01-31", "", "GMT+8"), &EN).unwrap();
+        let ids = custom_ids(&data.components.unwrap());
+        assert!(ids[0].ends_with("|1896019200|UTC+08:00|f"), "{}", ids[0]);
+        crate::util::abbr::init(2025);
+        let err = super::on_modal(&fields, &inputs("2030-01-31", "", "IST"), &EN).unwrap_err();
+        assert!(matches!(err, super::error::Error::AmbiguousTimezone { .. }));
+    }
+
+    #[test]
+    fn shift_and_switch() {
+        // 2025-03-08 12:00 in New York, a day before the spring-forward transition.
+        let data =
+            super::on_component(&["shift", "P1D", "1741453200", "America/New_York", "f"], &[], &EN)
+                .unwrap();
+        let ids = custom_ids(&data.components.unwrap());
+        // A calendar day later is only 23 hours later due to DST.
+        let expected = 1741453200 + 23 * 3600;
+        assert!(ids[0].ends_with(&format!("|{expected}|America/New_York|f")), "{}", ids[0]);
+
+        let data = super::on_component(
+            &["style", "1741453200", "America/New_York"],
+            &[String::from("R")],
+            &EN,
+        )
+        .unwrap();
+        let description = data.embeds.unwrap()[0].description.clone().unwrap();
+        assert!(description.contains("```<t:1741453200:R>```"));
+
+        let data = super::on_component(
+            &["zone", "1741453200", "America/New_York", "f"],
+            &[String::from("Asia/Tokyo")],
+            &EN,
+        )
+        .unwrap();
+        let ids = custom_ids(&data.components.unwrap());
+        let (_, fields) = custom_id::decode(&ids[0]).unwrap();
+        // Same wall-clock time (12:00) but in Tokyo.
+        assert_eq!(fields[2..], ["1741402800", "Asia/Tokyo", "f"]);
+    }
+}
@@ -1,160 +1,136 @@
+use super::{args, autocomplete, schema};
+use crate::{
+    i18n::{self, Catalog},
+    util::score,
+};
 use twilight_model::{
-    application::interaction::application_command::CommandData,
+    application::command::{Command, CommandOptionChoice, CommandOptionChoiceValue},
     channel::message::embed::{Embed, EmbedField},
     http::interaction::InteractionResponseData,
 };
 
-fn epoch() -> Embed {
+fn field(name: &str, value: impl Into<String>) -> EmbedField {
+    EmbedField { inline: false, name: String::from(name), value: value.into() }
+}
+
+fn epoch(catalog: &Catalog) -> Embed {
+    let help = &catalog.help;
+    let months = &catalog.calendar.months;
+    let month = i18n::fill(help.epoch_month, &[("first", months[0]), ("last", months[11])]);
     Embed {
-        title: Some(String::from("`/epoch` Command")),
-        description: Some(String::from(
-            "Generates the ISO-8601 timestamp at a given date and timezone.",
-        )),
+        title: Some(String::from(help.epoch_title)),
+        description: Some(String::from(help.epoch_description)),
         fields: Vec::from([
-            EmbedField {
-                inline: false,
-                name: String::from("`timezone`"),
-                value: String::from(
-                    "Required. Must be an officially registered timezone from the IANA Time Zone Database. For convenience, dynamic autocompletions are enabled.",
-                ),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`year`"),
-                value: String::from("Required. Must be a reasonably valid year."),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`month`"),
-                value: String::from(
-                    "Must be a value from `1` (default) to `12`, where `1` is January and `12` is December.",
-                ),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`day`"),
-                value: String::from(
-                    "Must be a value from `1` (default) to `31`. Note that the days `29` to `31` are only invalid for certain months.",
-                ),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`hour`"),
-                value: String::from(
-                    "Must be a value from `0` (default) to `23` (i.e. 24-hour format), where `0` is `12am` and `23` is `11pm`",
-                ),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`minute`"),
-                value: String::from("Must be a value from `0` (default) to `59`."),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`second`"),
-                value: String::from(
-                    "Must be a value from `0` (default) to `60`. The 60th second accounts for possible leap seconds.",
-                ),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`preview`"),
-                value: String::from(
-                    "Enables preview mode for all timestamp formatting options. Defaults to `true`.",
-                ),
-            },
+            field("`timezone`", help.epoch_timezone),
+            field("`year`", help.epoch_year),
+            field("`month`", month),
+            field("`day`", help.epoch_day),
+            field("`hour`", help.epoch_hour),
+            field("`minute`", help.epoch_minute),
+            field("`second`", help.epoch_second),
+            field("`preview`", help.epoch_preview),
         ]),
         ..super::embed::base()
     }
 }
 
-fn help() -> Embed {
+fn help(catalog: &Catalog) -> Embed {
+    let help = &catalog.help;
     Embed {
-        title: Some(String::from("`/help` Command")),
-        description: Some(String::from("Provides extra details for specific commands.")),
-        fields: Vec::from([
-            EmbedField {
-                inline: false,
-                name: String::from("`/epoch`"),
-                value: String::from(
-                    "Shows extra information for each argument of the `/epoch` command.",
-                ),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`/help`"),
-                value: String::from("Provides extra details on how to use the `/help` command."),
-            },
-        ]),
+        title: Some(String::from(help.help_title)),
+        description: Some(String::from(help.help_description)),
+        fields: Vec::from([field("`/epoch`", help.help_epoch), field("`/help`", help.help_help)]),
         ..super::embed::base()
     }
 }
 
-fn info() -> Embed {
+fn info(catalog: &Catalog) -> Embed {
+    let help = &catalog.help;
     Embed {
-        title: Some(String::from("Chrozone Information")),
-        description: Some(String::from(
-            "Show important information about the app: invite link, bug reports, and source code.",
-        )),
-        fields: Vec::from([EmbedField {
-            inline: false,
-            name: String::from("`/info`"),
-            value: String::from("Show the information page."),
-        }]),
+        title: Some(String::from(help.info_title)),
+        description: Some(String::from(help.info_description)),
+        fields: Vec::from([field("`/info`", help.info_info)]),
         ..super::embed::base()
     }
 }
 
-fn default() -> Embed {
+fn default(catalog: &Catalog) -> Embed {
+    let help = &catalog.help;
     Embed {
-        title: Some(String::from("Chrozone Help")),
-        description: Some(String::from("List of supported commands and their arguments.")),
+        title: Some(String::from(help.menu_title)),
+        description: Some(String::from(help.menu_description)),
         fields: Vec::from([
-            EmbedField {
-                inline: false,
-                name: String::from("`/help`"),
-                value: String::from("Summon this help menu."),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from("`/info`"),
-                value: String::from("Show the information page."),
-            },
-            EmbedField {
-                inline: false,
-                name: String::from(
-                    "`/epoch timezone year [month] [day] [hour] [min] [sec] [preview]`",
-                ),
-                value: String::from(
-                    "Get the ISO-8601 timestamp (in seconds) for some date and timezone.",
-                ),
-            },
+            field("`/help`", help.menu_help),
+            field("`/info`", help.info_info),
+            field(
+                "`/epoch [timezone] [year] [month] [day] [hour] [min] [sec] [preview]`",
+                help.menu_epoch,
+            ),
         ]),
         ..super::embed::base()
     }
 }
 
-pub fn execute(mut data: CommandData) -> Option<InteractionResponseData> {
-    use twilight_model::{
-        application::interaction::application_command::{CommandDataOption, CommandOptionValue},
-        channel::message::MessageFlags,
-    };
+/// Registered definition of the `/help` command.
+pub fn schema() -> Command {
+    use twilight_model::application::command::CommandOptionType;
+    schema::command(
+        "help",
+        |c| c.help,
+        Vec::from([schema::autocomplete(schema::option(
+            CommandOptionType::String,
+            "command",
+            |c| c.help_command,
+        ))]),
+    )
+}
+
+/// Autocompletes the commands which have a dedicated help page.
+pub fn topics(context: &autocomplete::Context) -> Vec<CommandOptionChoice> {
+    let query = context.query.trim().trim_start_matches('/');
+    score::rank(TOPICS, |name| name, query, &score::Subsequence, autocomplete::MAX_CHOICES)
+        .into_iter()
+        .map(|name| CommandOptionChoice {
+            name: format!("/{name}"),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(String::from(name)),
+        })
+        .collect()
+}
 
-    let get_embed = match data.options.pop() {
-        Some(CommandDataOption { value: CommandOptionValue::String(val), .. }) => {
-            match val.as_str() {
-                "epoch" => epoch,
-                "help" => help,
-                "info" => info,
-                _ => return None,
-            }
-        }
-        _ => default,
+/// Commands which have a dedicated help page.
+const TOPICS: [&str; 3] = ["epoch", "help", "info"];
+
+/// Parsed version of the [`TOPICS`].
+enum Topic {
+    Epoch,
+    Help,
+    Info,
+}
+
+args::choices!(Topic { "epoch" => Topic::Epoch, "help" => Topic::Help, "info" => Topic::Info });
+
+args::command_args! {
+    /// Arguments of the `/help` command.
+    pub struct Args {
+        command: Option<Topic> = "command",
+    }
+}
+
+/// Handler for the `/help` command.
+pub fn execute(args: Args, catalog: &Catalog) -> InteractionResponseData {
+    use twilight_model::channel::message::MessageFlags;
+
+    let get_embed = match args.command {
+        Some(Topic::Epoch) => epoch,
+        Some(Topic::Help) => help,
+        Some(Topic::Info) => info,
+        None => default,
     };
 
-    Some(InteractionResponseData {
-        embeds: Some(Vec::from([get_embed()])),
+    InteractionResponseData {
+        embeds: Some(Vec::from([get_embed(catalog)])),
         flags: Some(MessageFlags::EPHEMERAL),
         ..Default::default()
-    })
+    }
 }
@@ -1,4 +1,7 @@
+use super::schema;
+use crate::i18n::Catalog;
 use twilight_model::{
+    application::command::Command,
     channel::message::{
         Component, Embed, EmojiReactionType,
         component::{ActionRow, Button, ButtonStyle},
@@ -6,7 +9,12 @@ use twilight_model::{
     http::interaction::InteractionResponseData,
 };
 
-pub fn execute() -> InteractionResponseData {
+/// Registered definition of the `/info` command.
+pub fn schema() -> Command {
+    schema::command("info", |c| c.info, Vec::new())
+}
+
+pub fn execute(catalog: &Catalog) -> InteractionResponseData {
     InteractionResponseData {
         components: Some(vec![Component::ActionRow(ActionRow {
             id: None,
@@ -15,7 +23,7 @@ pub fn execute() -> InteractionResponseData {
                     id: None,
                     style: ButtonStyle::Link,
                     emoji: Some(EmojiReactionType::Unicode { name: String::from('🤖') }),
-                    label: Some(String::from("Install App")),
+                    label: Some(String::from(catalog.info.install)),
                     url: Some(String::from(
                         "https://discord.com/oauth2/authorize?client_id=1008989318901137459",
                     )),
@@ -27,7 +35,7 @@ pub fn execute() -> InteractionResponseData {
                     id: None,
                     style: ButtonStyle::Link,
                     emoji: Some(EmojiReactionType::Unicode { name: String::from('🐛') }),
-                    label: Some(String::from("Report a Bug")),
+                    label: Some(String::from(catalog.info.report)),
                     url: Some(String::from("https://github.com/BastiDood/chrozone/issues/new")),
                     custom_id: None,
                     sku_id: None,
@@ -37,7 +45,7 @@ pub fn execute() -> InteractionResponseData {
                     id: None,
                     style: ButtonStyle::Link,
                     emoji: Some(EmojiReactionType::Unicode { name: String::from('💻') }),
-                    label: Some(String::from("Fork the Code")),
+                    label: Some(String::from(catalog.info.fork)),
                     url: Some(String::from("https://github.com/BastiDood/chrozone/fork")),
                     custom_id: None,
                     sku_id: None,
@@ -46,9 +54,7 @@ pub fn execute() -> InteractionResponseData {
             ],
         })]),
         embeds: Some(vec![Embed {
-            description: Some(String::from(
-                "Chrozone is an [open-source](https://github.com/BastiDood/chrozone) bot written in [Rust](https://www.rust-lang.org/) by [`@BastiDood`](https://github.com/BastiDood) for time zone utilities and timestamp formatting.",
-            )),
+            description: Some(String::from(catalog.info.description)),
             ..super::embed::base()
         }]),
         ..Default::default()
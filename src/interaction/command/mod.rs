@@ -1,5 +1,411 @@
-use super::{embed, error};
+use super::{
+    browse, custom_id,
+    defer::{Deferral, Reply},
+    embed, error, share,
+};
+use crate::{State, i18n::Catalog};
+use args::FromCommandData;
+use std::sync::LazyLock;
+use twilight_model::{
+    application::{
+        command::{Command, CommandOption},
+        interaction::{
+            Interaction,
+            application_command::{CommandData, CommandDataOption},
+        },
+    },
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+};
 
+pub mod abbr;
+mod args;
+pub mod autocomplete;
+pub mod birthday;
+pub mod countdown;
+pub mod dst_alerts;
 pub mod epoch;
 pub mod help;
 pub mod info;
+pub mod route;
+mod schema;
+pub mod zones;
+
+/// Handler of a command (or subcommand) given the registered definitions of its options
+/// and the received ones, which it parses into its typed arguments.
+type Handler = fn(
+    &[CommandOption],
+    Vec<CommandDataOption>,
+    &Interaction,
+    &State,
+    &Catalog,
+) -> error::Result<Reply>;
+
+/// Wraps the message `data` as an immediate response.
+fn message(data: InteractionResponseData) -> Reply {
+    Reply::Now(InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(data),
+    })
+}
+
+/// Every command's registered definition. Both registration and dispatch are driven by
+/// these so that the two can never diverge.
+static DEFINITIONS: LazyLock<Box<[Command]>> = LazyLock::new(|| {
+    Box::new([
+        epoch::schema(),
+        help::schema(),
+        info::schema(),
+        countdown::schema(),
+        birthday::schema(),
+        dst_alerts::schema(),
+        abbr::schema(),
+        zones::schema(),
+    ])
+});
+
+/// Handlers keyed by the path of their (sub)command (see [`route::walk`]) along with how
+/// their responses are deferred, which must agree with the visibility of their replies.
+/// Those which may open a modal are never deferred since a modal cannot be sent late.
+static HANDLERS: [(&str, Option<Deferral>, Handler); 13] = [
+    ("epoch", None, |schema, options, interaction, state, catalog| {
+        let args: epoch::Args = FromCommandData::from_options(schema, options)?;
+        let zone = args.zone().map(Box::<str>::from);
+        let response = epoch::execute(args, catalog)?;
+        if let Some(zone) = zone {
+            zones::remember(interaction, state, &zone);
+            zones::learn(interaction, state, &zone);
+        }
+        Ok(Reply::Now(response))
+    }),
+    ("help", Some(Deferral::Ephemeral), |schema, options, _, _, catalog| {
+        Ok(message(help::execute(FromCommandData::from_options(schema, options)?, catalog)))
+    }),
+    ("info", Some(Deferral::Public), |schema, options, _, _, catalog| {
+        <()>::from_options(schema, options)?;
+        Ok(message(info::execute(catalog)))
+    }),
+    ("countdown", Some(Deferral::Public), |schema, options, interaction, state, _| {
+        let args = FromCommandData::from_options(schema, options)?;
+        countdown::execute(interaction, args, state).map(message)
+    }),
+    ("birthday set", Some(Deferral::Ephemeral), |schema, options, interaction, state, _| {
+        let args = FromCommandData::from_options(schema, options)?;
+        birthday::set(interaction, args, state).map(message)
+    }),
+    ("birthday unset", Some(Deferral::Ephemeral), |schema, options, interaction, state, _| {
+        <()>::from_options(schema, options)?;
+        birthday::unset(interaction, state).map(message)
+    }),
+    (
+        "dst-alerts subscribe",
+        Some(Deferral::Ephemeral),
+        |schema, options, interaction, state, _| {
+            let args = FromCommandData::from_options(schema, options)?;
+            dst_alerts::subscribe(interaction, args, state).map(message)
+        },
+    ),
+    (
+        "dst-alerts unsubscribe",
+        Some(Deferral::Ephemeral),
+        |schema, options, interaction, state, _| {
+            let args = FromCommandData::from_options(schema, options)?;
+            dst_alerts::unsubscribe(interaction, args, state).map(message)
+        },
+    ),
+    ("abbr lookup", Some(Deferral::Public), |schema, options, _, state, _| {
+        let args = FromCommandData::from_options(schema, options)?;
+        abbr::lookup(args, &state.abbreviations).map(message)
+    }),
+    ("abbr zone", Some(Deferral::Public), |schema, options, _, state, _| {
+        let args = FromCommandData::from_options(schema, options)?;
+        abbr::zone(args, &state.abbreviations).map(message)
+    }),
+    ("zones favorite", Some(Deferral::Ephemeral), |schema, options, interaction, state, _| {
+        let args = FromCommandData::from_options(schema, options)?;
+        zones::favorite(interaction, args, state).map(message)
+    }),
+    ("zones unfavorite", Some(Deferral::Ephemeral), |schema, options, interaction, state, _| {
+        let args = FromCommandData::from_options(schema, options)?;
+        zones::unfavorite(interaction, args, state).map(message)
+    }),
+    ("zones history", Some(Deferral::Ephemeral), |schema, options, interaction, state, _| {
+        let args = FromCommandData::from_options(schema, options)?;
+        zones::history(interaction, args, state).map(message)
+    }),
+];
+
+/// The registered definitions of every command.
+pub fn definitions() -> impl Iterator<Item = &'static Command> {
+    DEFINITIONS.iter()
+}
+
+/// How the response to the invoked (sub)command is deferred (if it is known at all).
+pub fn deferral(data: &CommandData) -> Option<Deferral> {
+    let path = route::path(data);
+    HANDLERS.iter().find(|(other, ..)| *other == path).and_then(|(_, deferral, _)| *deferral)
+}
+
+/// Dispatches the command to the handler of its (sub)command, which parses the options as
+/// registered. If the registered commands are known, the command is resolved by its ID.
+/// Otherwise, it falls back to the built-in definition of the same name.
+pub fn execute(
+    interaction: &Interaction,
+    data: CommandData,
+    state: &State,
+    catalog: &Catalog,
+) -> error::Result<Reply> {
+    let command = match &state.commands {
+        Some(registry) => registry
+            .resolve(data.id, &data.name, interaction.guild_id)
+            .ok_or(error::Error::UnregisteredCommand)?,
+        None => definitions().find(|command| command.name == data.name).ok_or_else(|| {
+            log::error!("Invoked unknown /{} command.", data.name);
+            error::Error::UnknownCommand
+        })?,
+    };
+
+    let route::Route { path, schema, options } = route::walk(command, data.options)?;
+    let Some((_, _, handler)) = HANDLERS.iter().find(|(other, ..)| *other == path) else {
+        log::error!("Invoked /{path} without a handler.");
+        return Err(error::Error::UnknownCommand);
+    };
+
+    handler(schema, options, interaction, state, catalog)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::args::FromCommandData;
+    use crate::rest::{self, mock::Mock};
+    use twilight_model::{
+        application::{
+            command::CommandOption,
+            interaction::application_command::{CommandDataOption, CommandOptionValue},
+        },
+        id::Id,
+    };
+
+    fn definition(name: &str) -> &'static [CommandOption] {
+        let command = super::definitions().find(|command| command.name == name).unwrap();
+        &command.options
+    }
+
+    fn option(name: &str, value: CommandOptionValue) -> CommandDataOption {
+        CommandDataOption { name: name.into(), value }
+    }
+
+    #[test]
+    fn descriptions_fit() {
+        fn check(text: &str) {
+            assert!(text.chars().count() <= 100, "description too long: {text}");
+        }
+
+        fn check_option(option: &CommandOption) {
+            check(&option.description);
+            option
+                .description_localizations
+                .iter()
+                .flat_map(|map| map.values())
+                .for_each(|text| check(text));
+            option.options.iter().flatten().for_each(check_option);
+        }
+
+        for command in super::definitions() {
+            check(&command.description);
+            command
+                .description_localizations
+                .iter()
+                .flat_map(|map| map.values())
+                .for_each(|text| check(text));
+            command.options.iter().for_each(check_option);
+        }
+    }
+
+    #[test]
+    fn names_are_localized() {
+        use std::collections::HashMap;
+
+        /// Checks the translations of the `names` of siblings, which must be valid and
+        /// distinct in every locale.
+        fn check<'a>(names: impl Iterator<Item = (&'a str, Option<&'a HashMap<String, String>>)>) {
+            let mut seen = Vec::new();
+            for (name, localizations) in names {
+                let localizations = localizations.unwrap_or_else(|| panic!("{name} untranslated"));
+                assert!(localizations.contains_key("es-ES"), "{name} untranslated");
+                for (locale, localized) in localizations {
+                    assert!(
+                        localized.chars().count() <= 32
+                            && *localized == localized.to_lowercase()
+                            && localized.chars().all(|c| c.is_alphanumeric() || c == '-'),
+                        "invalid {locale} name {localized}"
+                    );
+                    assert!(!seen.contains(&(locale, localized)), "duplicate {locale} {localized}");
+                    seen.push((locale, localized));
+                }
+            }
+        }
+
+        fn check_options(options: &[CommandOption]) {
+            check(options.iter().map(|option| (&*option.name, option.name_localizations.as_ref())));
+            for option in options {
+                check_options(option.options.as_deref().unwrap_or_default());
+            }
+        }
+
+        let commands: Vec<_> = super::definitions().collect();
+        check(commands.iter().map(|command| (&*command.name, command.name_localizations.as_ref())));
+        for command in commands {
+            check_options(&command.options);
+        }
+    }
+
+    #[test]
+    fn parse_options_as_defined() {
+        use super::{birthday, epoch, error::Error};
+        let epoch = |options| {
+            epoch::Args::from_options(definition("epoch"), options).err().map(|err| match err {
+                Error::InvalidOption(option) => format!("invalid {option}"),
+                Error::MissingOption(option) => format!("missing {option}"),
+                Error::OptionOutOfRange { option, min, max } => {
+                    format!("{option} in {min}..={max}")
+                }
+                other => format!("{other:?}"),
+            })
+        };
+        assert_eq!(epoch(Vec::new()), None);
+        assert_eq!(epoch(Vec::from([option("year", CommandOptionValue::Integer(2030))])), None);
+        assert_eq!(
+            epoch(Vec::from([option("year", CommandOptionValue::String("2030".into()))]))
+                .as_deref(),
+            Some("invalid year"),
+        );
+        assert_eq!(
+            epoch(Vec::from([option("secs", CommandOptionValue::Integer(0))])).as_deref(),
+            Some("invalid secs"),
+        );
+        assert_eq!(
+            epoch(Vec::from([option("day", CommandOptionValue::Integer(32))])).as_deref(),
+            Some("day in 1..=31"),
+        );
+        assert_eq!(
+            epoch(Vec::from([option("month", CommandOptionValue::Integer(0))])).as_deref(),
+            Some("month in 1..=12"),
+        );
+        assert_eq!(
+            epoch(Vec::from([option("year", CommandOptionValue::Integer(100_000))])).as_deref(),
+            Some("year in -32768..=32767"),
+        );
+
+        let command = super::definitions().find(|command| command.name == "birthday").unwrap();
+        let birthday = |options| {
+            let route = super::route::walk(command, options)?;
+            let args = birthday::SetArgs::from_options(route.schema, route.options)?;
+            Ok::<_, Error>((route.path, args))
+        };
+        let set = |options| Vec::from([option("set", CommandOptionValue::SubCommand(options))]);
+        let date = option("date", CommandOptionValue::String("02-29".into()));
+        assert!(
+            matches!(birthday(set(Vec::from([date.clone()]))), Ok((path, _)) if path == "birthday set")
+        );
+        assert!(
+            matches!(birthday(set(Vec::new())), Err(Error::MissingOption(option)) if &*option == "date")
+        );
+        let fallback = option("fallback", CommandOptionValue::String("feb-29".into()));
+        assert!(matches!(
+            birthday(set(Vec::from([date.clone(), fallback]))),
+            Err(Error::InvalidOption(option)) if &*option == "fallback",
+        ));
+        assert!(
+            matches!(birthday(Vec::from([date])), Err(Error::InvalidOption(option)) if &*option == "date")
+        );
+    }
+
+    #[test]
+    fn every_route_has_handler() {
+        use twilight_model::application::command::CommandOptionType;
+
+        /// Collects the paths of every innermost (sub)command.
+        fn leaves(path: String, options: &[CommandOption], paths: &mut Vec<String>) {
+            let mut nested = options.iter().filter(|option| {
+                matches!(
+                    option.kind,
+                    CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+                )
+            });
+            let Some(first) = nested.next() else {
+                paths.push(path);
+                return;
+            };
+            for option in core::iter::once(first).chain(nested) {
+                let inner = option.options.as_deref().unwrap_or_default();
+                leaves(format!("{path} {}", option.name), inner, paths);
+            }
+        }
+
+        let mut paths = Vec::new();
+        for command in super::definitions() {
+            leaves(command.name.clone(), &command.options, &mut paths);
+        }
+        let mut handled: Vec<_> =
+            super::HANDLERS.iter().map(|(path, ..)| String::from(*path)).collect();
+        paths.sort_unstable();
+        handled.sort_unstable();
+        assert_eq!(paths, handled);
+    }
+
+    #[test]
+    fn modal_routes_are_never_deferred() {
+        use twilight_model::application::{
+            command::CommandType, interaction::application_command::CommandData,
+        };
+        let data = |name: &str| CommandData {
+            guild_id: None,
+            id: Id::new(1),
+            name: name.into(),
+            kind: CommandType::ChatInput,
+            options: Vec::new(),
+            resolved: None,
+            target_id: None,
+        };
+
+        // Without any date options, `/epoch` prompts with a modal.
+        let schema = definition("epoch");
+        let args = super::epoch::Args::from_options(schema, Vec::new()).ok().unwrap();
+        let response = super::epoch::execute(args, &crate::i18n::EN).ok().unwrap();
+        assert_eq!(
+            response.kind,
+            twilight_model::http::interaction::InteractionResponseType::Modal
+        );
+        assert_eq!(super::deferral(&data("epoch")), None);
+        assert_eq!(super::deferral(&data("info")), Some(super::Deferral::Public));
+    }
+
+    #[tokio::test]
+    async fn registers_every_definition() {
+        let mock = Mock::start().await;
+        mock.reply_with("[]");
+        let client = rest::Client::new(mock.url(), Some("secret".into()));
+
+        crate::register(&client, Id::new(1), None).await.ok().unwrap();
+        crate::register(&client, Id::new(1), Some(Id::new(2))).await.ok().unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|request| request.method == "PUT"));
+        assert!(
+            requests.iter().all(|request| request.authorization.as_deref() == Some("Bot secret"))
+        );
+        assert_eq!(requests[0].path, "/applications/1/commands");
+        assert_eq!(requests[1].path, "/applications/1/guilds/2/commands");
+
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        let commands = body.as_array().unwrap();
+        assert_eq!(commands.len(), 8);
+        let epoch = commands.iter().find(|command| command["name"] == "epoch").unwrap();
+        assert!(
+            epoch["options"].as_array().unwrap().iter().any(|option| option["name"] == "second")
+        );
+        assert!(epoch["description_localizations"]["es-ES"].is_string());
+        assert_eq!(epoch["name_localizations"]["pt-BR"], "época");
+    }
+}
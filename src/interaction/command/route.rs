@@ -0,0 +1,214 @@
+//! Routing of commands through their (possibly nested) subcommands and subcommand groups.
+//! Handlers are keyed by their path, which is the command name followed by the names of
+//! every subcommand group and subcommand along the way (e.g., `birthday set`).
+
+use super::{args, error};
+use twilight_model::application::{
+    command::{Command, CommandOption, CommandOptionType},
+    interaction::application_command::{CommandData, CommandDataOption, CommandOptionValue},
+};
+
+/// A command invocation resolved down to its innermost (sub)command.
+pub struct Route<'s> {
+    /// Space-separated names from the command down to the innermost subcommand.
+    pub path: String,
+    /// Registered definitions of the options of the innermost (sub)command.
+    pub schema: &'s [CommandOption],
+    /// Received options of the innermost (sub)command.
+    pub options: Vec<CommandDataOption>,
+}
+
+/// Walks the received `options` of the registered `command` through every subcommand
+/// group and subcommand, validating each against its registered definition.
+pub fn walk(command: &Command, mut options: Vec<CommandDataOption>) -> error::Result<Route<'_>> {
+    let mut path = command.name.clone();
+    let mut schema = command.options.as_slice();
+    while let [CommandDataOption { value, .. }] = options.as_slice()
+        && is_nested(value.kind())
+    {
+        let Some(CommandDataOption { name, value }) = options.pop() else {
+            unreachable!("exactly one option exists");
+        };
+        let definition = args::validate(schema, &name, &value)?;
+        let (CommandOptionValue::SubCommand(inner) | CommandOptionValue::SubCommandGroup(inner)) =
+            value
+        else {
+            unreachable!("nested options are either subcommands or subcommand groups");
+        };
+        path.push(' ');
+        path.push_str(&name);
+        schema = definition.options.as_deref().unwrap_or_default();
+        options = inner;
+    }
+    Ok(Route { path, schema, options })
+}
+
+/// Finds the path of the (sub)command invoked by the `data` without validating it against
+/// the registered definitions, which suffices for looking up the declarations of its handler.
+pub fn path(data: &CommandData) -> String {
+    let mut path = data.name.clone();
+    let mut options = data.options.as_slice();
+    while let [CommandDataOption { name, value }] = options
+        && let CommandOptionValue::SubCommand(inner) | CommandOptionValue::SubCommandGroup(inner) =
+            value
+    {
+        path.push(' ');
+        path.push_str(name);
+        options = inner;
+    }
+    path
+}
+
+/// The option currently being typed by the user (for autocompletion).
+pub struct Focused<'d> {
+    /// Space-separated names from the command down to the (sub)command of the option.
+    pub path: String,
+    pub name: &'d str,
+    pub value: &'d str,
+    /// Every option at the same level as the focused one (including itself), which gives
+    /// context about the other options that have already been filled.
+    pub options: &'d [CommandDataOption],
+}
+
+/// Finds the focused option at any depth of subcommand groups and subcommands.
+pub fn focused(data: &CommandData) -> Option<Focused<'_>> {
+    fn find<'d>(path: &mut String, options: &'d [CommandDataOption]) -> Option<Focused<'d>> {
+        for CommandDataOption { name, value } in options {
+            match value {
+                CommandOptionValue::Focused(value, _) => {
+                    return Some(Focused { path: String::new(), name, value, options });
+                }
+                CommandOptionValue::SubCommand(inner)
+                | CommandOptionValue::SubCommandGroup(inner) => {
+                    path.push(' ');
+                    path.push_str(name);
+                    return find(path, inner);
+                }
+                _ => (),
+            }
+        }
+        None
+    }
+
+    let mut path = data.name.clone();
+    let focused = find(&mut path, &data.options)?;
+    Some(Focused { path, ..focused })
+}
+
+fn is_nested(kind: CommandOptionType) -> bool {
+    matches!(kind, CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup)
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::application::{
+        command::CommandOptionType,
+        interaction::application_command::{CommandDataOption, CommandOptionValue},
+    };
+
+    fn option(name: &str, value: CommandOptionValue) -> CommandDataOption {
+        CommandDataOption { name: name.into(), value }
+    }
+
+    /// `/tz convert from:<zone> to:<zone>` and `/tz info zone:<zone>` within a
+    /// `zones` subcommand group alongside a plain `/tz now` subcommand.
+    fn tz() -> twilight_model::application::command::Command {
+        use super::super::schema::{command, option, subcommand};
+        use twilight_model::application::command::CommandOption;
+        let zone = |name| option(CommandOptionType::String, name, |c| c.epoch_timezone);
+        let group = CommandOption {
+            kind: CommandOptionType::SubCommandGroup,
+            ..subcommand(
+                "zones",
+                |c| c.help,
+                Vec::from([
+                    subcommand("convert", |c| c.help, Vec::from([zone("from"), zone("to")])),
+                    subcommand("info", |c| c.help, Vec::from([zone("zone")])),
+                ]),
+            )
+        };
+        command("tz", |c| c.help, Vec::from([subcommand("now", |c| c.help, Vec::new()), group]))
+    }
+
+    #[test]
+    fn walk_nested_paths() {
+        let tz = tz();
+        let route = super::walk(
+            &tz,
+            Vec::from([option("now", CommandOptionValue::SubCommand(Vec::new()))]),
+        )
+        .ok()
+        .unwrap();
+        assert_eq!(route.path, "tz now");
+        assert!(route.options.is_empty());
+
+        let convert = Vec::from([
+            option("from", CommandOptionValue::String("Asia/Manila".into())),
+            option("to", CommandOptionValue::String("UTC".into())),
+        ]);
+        let options = Vec::from([option(
+            "zones",
+            CommandOptionValue::SubCommandGroup(Vec::from([option(
+                "convert",
+                CommandOptionValue::SubCommand(convert),
+            )])),
+        )]);
+        let route = super::walk(&tz, options).ok().unwrap();
+        assert_eq!(route.path, "tz zones convert");
+        assert_eq!(route.schema.len(), 2);
+        assert_eq!(route.options.len(), 2);
+
+        // Subcommands must be registered at their level.
+        let options = Vec::from([option("convert", CommandOptionValue::SubCommand(Vec::new()))]);
+        assert!(super::walk(&tz, options).is_err());
+    }
+
+    #[test]
+    fn focus_at_any_depth() {
+        use twilight_model::{
+            application::{command::CommandType, interaction::application_command::CommandData},
+            id::Id,
+        };
+        let data = |name: &str, options| CommandData {
+            guild_id: None,
+            id: Id::new(1),
+            name: name.into(),
+            kind: CommandType::ChatInput,
+            options,
+            resolved: None,
+            target_id: None,
+        };
+        let focus =
+            |value: &str| CommandOptionValue::Focused(value.into(), CommandOptionType::String);
+
+        let flat = data(
+            "epoch",
+            Vec::from([
+                option("year", CommandOptionValue::Integer(2030)),
+                option("timezone", focus("manila")),
+            ]),
+        );
+        let focused = super::focused(&flat).unwrap();
+        assert_eq!(
+            (focused.path.as_str(), focused.name, focused.value),
+            ("epoch", "timezone", "manila")
+        );
+
+        let info = Vec::from([option("zone", focus("tok"))]);
+        let info = Vec::from([option("info", CommandOptionValue::SubCommand(info))]);
+        let nested =
+            data("tz", Vec::from([option("zones", CommandOptionValue::SubCommandGroup(info))]));
+        let focused = super::focused(&nested).unwrap();
+        assert_eq!(
+            (focused.path.as_str(), focused.name, focused.value),
+            ("tz zones info", "zone", "tok")
+        );
+
+        let none = data("epoch", Vec::from([option("year", CommandOptionValue::Integer(2030))]));
+        assert!(super::focused(&none).is_none());
+
+        // The path alone is found without the registered definitions.
+        assert_eq!(super::path(&nested), "tz zones info");
+        assert_eq!(super::path(&none), "epoch");
+    }
+}
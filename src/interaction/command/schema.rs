@@ -0,0 +1,134 @@
+//! Builders for the registered definitions of the commands. Names and descriptions are
+//! taken from the message catalogs so that every translation is registered along with the
+//! English.
+
+use crate::i18n::{self, Catalog, Commands, Names};
+use std::collections::HashMap;
+use twilight_model::{
+    application::{
+        command::{
+            Command, CommandOption, CommandOptionChoice, CommandOptionChoiceValue,
+            CommandOptionType, CommandType,
+        },
+        interaction::InteractionContextType,
+    },
+    id::Id,
+    oauth::ApplicationIntegrationType,
+};
+
+/// Selects a description from the catalog.
+pub type Description = fn(&Commands) -> &'static str;
+
+/// Translates the `text` into every Discord locale for which a catalog exists.
+pub fn localizations(text: impl Fn(&Catalog) -> String) -> Option<HashMap<String, String>> {
+    let localizations: HashMap<_, _> = i18n::TRANSLATIONS
+        .iter()
+        .flat_map(|catalog| {
+            let text = text(catalog);
+            catalog.locales.iter().map(move |locale| (String::from(*locale), text.clone()))
+        })
+        .collect();
+    (!localizations.is_empty()).then_some(localizations)
+}
+
+/// Translates the English `name` of a command or an option into every Discord locale for
+/// which a catalog exists. Names missing from the catalogs are not translated.
+fn names(name: &str) -> Option<HashMap<String, String>> {
+    let select = Names::selector(name)?;
+    localizations(|catalog| String::from(select(&catalog.names)))
+}
+
+/// Creates a user-installed slash command which is available everywhere.
+pub fn command(name: &str, description: Description, options: Vec<CommandOption>) -> Command {
+    #[expect(deprecated, reason = "the availability is set through the contexts instead")]
+    Command {
+        application_id: None,
+        contexts: Some(Vec::from([
+            InteractionContextType::Guild,
+            InteractionContextType::BotDm,
+            InteractionContextType::PrivateChannel,
+        ])),
+        default_member_permissions: None,
+        dm_permission: None,
+        description: String::from(description(&i18n::EN.commands)),
+        description_localizations: localizations(|catalog| {
+            String::from(description(&catalog.commands))
+        }),
+        guild_id: None,
+        id: None,
+        integration_types: Some(Vec::from([ApplicationIntegrationType::UserInstall])),
+        kind: CommandType::ChatInput,
+        name: String::from(name),
+        name_localizations: names(name),
+        nsfw: None,
+        options,
+        version: Id::new(1),
+    }
+}
+
+/// Restricts the `command` to servers which have installed the app.
+pub fn guild_only(command: Command) -> Command {
+    Command {
+        contexts: Some(Vec::from([InteractionContextType::Guild])),
+        integration_types: Some(Vec::from([ApplicationIntegrationType::GuildInstall])),
+        ..command
+    }
+}
+
+/// Creates an optional option of the given `kind`.
+pub fn option(kind: CommandOptionType, name: &str, description: Description) -> CommandOption {
+    CommandOption {
+        autocomplete: None,
+        channel_types: None,
+        choices: None,
+        description: String::from(description(&i18n::EN.commands)),
+        description_localizations: localizations(|catalog| {
+            String::from(description(&catalog.commands))
+        }),
+        kind,
+        max_length: None,
+        max_value: None,
+        min_length: None,
+        min_value: None,
+        name: String::from(name),
+        name_localizations: names(name),
+        options: None,
+        required: None,
+    }
+}
+
+/// Marks the `option` as required.
+pub fn required(option: CommandOption) -> CommandOption {
+    CommandOption { required: Some(true), ..option }
+}
+
+/// Enables autocompletion for the `option`. See the providers in [`super::autocomplete`].
+pub fn autocomplete(option: CommandOption) -> CommandOption {
+    CommandOption { autocomplete: Some(true), ..option }
+}
+
+/// Creates a subcommand with the given `options`.
+pub fn subcommand(
+    name: &str,
+    description: Description,
+    options: Vec<CommandOption>,
+) -> CommandOption {
+    CommandOption {
+        options: Some(options),
+        ..option(CommandOptionType::SubCommand, name, description)
+    }
+}
+
+/// Creates a choice whose name is translated via the `name` selector.
+pub fn choice(
+    name: impl Fn(&Catalog) -> String,
+    value: CommandOptionChoiceValue,
+) -> CommandOptionChoice {
+    CommandOptionChoice { name: name(&i18n::EN), name_localizations: localizations(name), value }
+}
+
+/// Capitalizes the first letter of the `text` (e.g., for month names as choices).
+pub fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
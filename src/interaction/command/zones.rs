@@ -0,0 +1,300 @@
+use super::{args, autocomplete, error, schema};
+use crate::{
+    State,
+    store::Preferences,
+    util::{index, score, tz},
+};
+use twilight_model::{
+    application::{
+        command::{Command, CommandOptionChoice, CommandOptionChoiceValue},
+        interaction::Interaction,
+    },
+    channel::message::MessageFlags,
+    http::interaction::InteractionResponseData,
+};
+
+/// Maximum number of recent zones kept per user.
+const MAX_RECENT: usize = 5;
+
+/// Maximum number of favorite zones per user, which leaves room for other suggestions.
+const MAX_FAVORITES: usize = 10;
+
+args::command_args! {
+    /// Arguments of the `/zones favorite` and `/zones unfavorite` subcommands.
+    pub struct ZoneArgs {
+        zone: String = "zone",
+    }
+}
+
+args::command_args! {
+    /// Arguments of the `/zones history` subcommand.
+    pub struct HistoryArgs {
+        enabled: bool = "enabled",
+    }
+}
+
+/// Wraps the `content` as a private reply.
+fn reply(content: String) -> InteractionResponseData {
+    InteractionResponseData {
+        content: Some(content),
+        flags: Some(MessageFlags::EPHEMERAL),
+        ..Default::default()
+    }
+}
+
+/// Resolves the `text` into the name of a zone in the database, which is the only kind of
+/// zone that autocompletion may suggest.
+fn canonical(text: &str) -> Option<&'static str> {
+    let zone = tz::resolve(text).ok()?;
+    index::TIMEZONES.name(&zone.name)
+}
+
+/// Records the `zone` submitted by the invoker as their most recent one unless they have
+/// opted out of the history. Failures are logged since they must not fail the command.
+pub fn remember(interaction: &Interaction, state: &State, zone: &str) {
+    let (Some(user), Some(zone)) = (interaction.author_id(), canonical(zone)) else {
+        return;
+    };
+    let is_known = state.store.read(|db| {
+        db.preferences.iter().find(|preferences| preferences.user == user).is_some_and(
+            |preferences| {
+                preferences.no_history
+                    || preferences.recent.first().is_some_and(|recent| **recent == *zone)
+            },
+        )
+    });
+    if is_known {
+        return;
+    }
+
+    let result = state.store.write(|db| {
+        let preferences = db.preferences_mut(user);
+        if !preferences.no_history {
+            preferences.recent.retain(|other| **other != *zone);
+            preferences.recent.insert(0, zone.into());
+            preferences.recent.truncate(MAX_RECENT);
+        }
+    });
+    if let Err(err) = result {
+        log::error!("Failed to persist recent timezone: {err}.");
+    }
+}
+
+/// Learns the popularity of the `zone` submitted by the invoker from the queries of their
+/// preceding autocompletion session (if any). Users who opted out of the history are never
+/// observed in the first place.
+pub fn learn(interaction: &Interaction, state: &State, zone: &str) {
+    let (Some(user), Some(zone)) = (interaction.author_id(), canonical(zone)) else {
+        return;
+    };
+    let now = state.clock.now();
+    let Some(queries) = state.learner.conclude(user.get(), zone, now) else {
+        return;
+    };
+    let prior = &state.learner.prior;
+    let result = state
+        .store
+        .write(|db| db.popularity.record(queries.iter().map(|query| &**query), zone, now, prior));
+    if let Err(err) = result {
+        log::error!("Failed to persist timezone popularity: {err}.");
+    }
+}
+
+/// Mutates the preferences of the invoker.
+fn update<R>(
+    interaction: &Interaction,
+    state: &State,
+    f: impl FnOnce(&mut Preferences) -> R,
+) -> error::Result<R> {
+    let user = interaction.author_id().ok_or(error::Error::MissingPayload)?;
+    state.store.write(|db| f(db.preferences_mut(user))).map_err(|err| {
+        log::error!("Failed to persist timezone preferences: {err}.");
+        error::Error::Storage
+    })
+}
+
+/// Handler for the `/zones favorite` subcommand.
+pub fn favorite(
+    interaction: &Interaction,
+    ZoneArgs { zone }: ZoneArgs,
+    state: &State,
+) -> error::Result<InteractionResponseData> {
+    let zone = canonical(&zone).ok_or(error::Error::UnknownTimezone)?;
+    let pinned = update(interaction, state, |preferences| {
+        if preferences.favorites.iter().any(|other| **other == *zone) {
+            Some(true)
+        } else if preferences.favorites.len() >= MAX_FAVORITES {
+            None
+        } else {
+            preferences.favorites.push(zone.into());
+            Some(false)
+        }
+    })?;
+    Ok(reply(match pinned {
+        Some(false) => format!("**{zone}** will now be suggested first."),
+        Some(true) => format!("**{zone}** is already one of your favorites."),
+        None => {
+            format!("You may only have up to {MAX_FAVORITES} favorites. Please remove one first.")
+        }
+    }))
+}
+
+/// Handler for the `/zones unfavorite` subcommand. The zone is matched as is so that zones
+/// since removed from the database may still be removed.
+pub fn unfavorite(
+    interaction: &Interaction,
+    ZoneArgs { zone }: ZoneArgs,
+    state: &State,
+) -> error::Result<InteractionResponseData> {
+    let zone = canonical(&zone).unwrap_or(zone.trim());
+    let removed = update(interaction, state, |preferences| {
+        let before = preferences.favorites.len();
+        preferences.favorites.retain(|other| **other != *zone);
+        before != preferences.favorites.len()
+    })?;
+    Ok(reply(if removed {
+        format!("**{zone}** is no longer one of your favorites.")
+    } else {
+        format!("**{zone}** is not one of your favorites.")
+    }))
+}
+
+/// Handler for the `/zones history` subcommand. Disabling the history also forgets it.
+pub fn history(
+    interaction: &Interaction,
+    HistoryArgs { enabled }: HistoryArgs,
+    state: &State,
+) -> error::Result<InteractionResponseData> {
+    update(interaction, state, |preferences| {
+        preferences.no_history = !enabled;
+        if !enabled {
+            preferences.recent.clear();
+        }
+    })?;
+    Ok(reply(String::from(if enabled {
+        "Your recently used timezones will now be suggested first."
+    } else {
+        "Your recently used timezones have been forgotten and will no longer be recorded."
+    })))
+}
+
+/// Autocompletes the favorite zones of the invoker.
+pub fn favorites(context: &autocomplete::Context) -> Vec<CommandOptionChoice> {
+    let Some(user) = context.interaction.author_id() else {
+        return Vec::new();
+    };
+    context.state.store.read(|db| {
+        let preferences = db.preferences.iter().find(|preferences| preferences.user == user);
+        let favorites = preferences.into_iter().flat_map(|preferences| &preferences.favorites);
+        score::rank(favorites, |zone| zone, context.query, &score::Subsequence, MAX_FAVORITES)
+            .into_iter()
+            .map(|zone| CommandOptionChoice {
+                name: zone.replace('_', " "),
+                name_localizations: None,
+                value: CommandOptionChoiceValue::String(String::from(&**zone)),
+            })
+            .collect()
+    })
+}
+
+/// Registered definition of the `/zones` command.
+pub fn schema() -> Command {
+    use schema::{autocomplete, option, required, subcommand};
+    use twilight_model::application::command::CommandOptionType::{Boolean, String};
+    schema::command(
+        "zones",
+        |c| c.zones,
+        Vec::from([
+            subcommand(
+                "favorite",
+                |c| c.zones_favorite,
+                Vec::from([required(autocomplete(option(String, "zone", |c| {
+                    c.zones_favorite_zone
+                })))]),
+            ),
+            subcommand(
+                "unfavorite",
+                |c| c.zones_unfavorite,
+                Vec::from([required(autocomplete(option(String, "zone", |c| {
+                    c.zones_unfavorite_zone
+                })))]),
+            ),
+            subcommand(
+                "history",
+                |c| c.zones_history,
+                Vec::from([required(option(Boolean, "enabled", |c| c.zones_history_enabled))]),
+            ),
+        ]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HistoryArgs, ZoneArgs};
+    use crate::{State, job::SystemClock, rest, store::Store};
+    use twilight_model::application::interaction::Interaction;
+
+    fn interaction() -> Interaction {
+        serde_json::from_value(serde_json::json!({
+            "application_id": "1",
+            "authorizing_integration_owners": {},
+            "data": { "custom_id": "x", "component_type": 2 },
+            "entitlements": [],
+            "id": "2",
+            "token": "tok",
+            "type": 3,
+            "user": {
+                "avatar": null,
+                "discriminator": "0",
+                "id": "5",
+                "username": "someone",
+            },
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    fn state() -> State {
+        State {
+            pub_key: ed25519_dalek::SigningKey::from_bytes(&[1; 32]).verifying_key(),
+            store: Store::in_memory(),
+            rest: rest::Client::new("http://localhost", None),
+            clock: Box::new(SystemClock),
+            budget: core::time::Duration::from_secs(2),
+            commands: None,
+            abbreviations: Default::default(),
+            learner: Default::default(),
+        }
+    }
+
+    fn preferences(state: &State) -> (Vec<Box<str>>, Vec<Box<str>>) {
+        state.store.read(|db| {
+            let preferences = &db.preferences[0];
+            (preferences.favorites.clone(), preferences.recent.clone())
+        })
+    }
+
+    #[test]
+    fn remembers_unless_opted_out() {
+        let (interaction, state) = (interaction(), state());
+        for zone in ["Asia/Manila", "GMT+8", "Europe/Berlin", "asia/manila", "UTC+08:00"] {
+            super::remember(&interaction, &state, zone);
+        }
+        // Fixed offsets cannot be suggested, so they are not remembered.
+        assert_eq!(preferences(&state).1, ["Asia/Manila", "Europe/Berlin"].map(Box::from));
+
+        let args = ZoneArgs { zone: String::from("Europe/Berlin") };
+        super::favorite(&interaction, args, &state).unwrap();
+        assert_eq!(preferences(&state).0, [Box::from("Europe/Berlin")]);
+
+        super::history(&interaction, HistoryArgs { enabled: false }, &state).unwrap();
+        super::remember(&interaction, &state, "Asia/Tokyo");
+        let (favorites, recent) = preferences(&state);
+        assert_eq!(favorites.len(), 1);
+        assert!(recent.is_empty());
+
+        let args = ZoneArgs { zone: String::from("Europe/Berlin") };
+        super::unfavorite(&interaction, args, &state).unwrap();
+        assert!(preferences(&state).0.is_empty());
+    }
+}
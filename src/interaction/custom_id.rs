@@ -0,0 +1,86 @@
+//! Stateless encoding of component state into `custom_id` strings.
+//!
+//! Every `custom_id` has the shape `{VERSION}|{kind}|{field}|{field}|...`. The `kind`
+//! selects the handler whereas the fields carry whatever state the handler needs to
+//! re-render the message. Since the format is versioned, components of messages sent by
+//! older deployments are gracefully rejected instead of being misinterpreted.
+
+/// Current version of the encoding scheme.
+pub const VERSION: &str = "1";
+
+/// Maximum length of a `custom_id` as imposed by Discord.
+pub const MAX_LEN: usize = 100;
+
+const SEPARATOR: char = '|';
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The encoded string exceeds [`MAX_LEN`].
+    TooLong,
+    /// A field contains the separator character.
+    InvalidField,
+    /// The string was encoded by an unsupported version of the scheme.
+    Version,
+    /// The string is not in the expected shape.
+    Malformed,
+}
+
+/// Encodes the `kind` and its `fields` into a `custom_id`.
+pub fn encode<S: AsRef<str>>(kind: &str, fields: &[S]) -> Result<String, Error> {
+    let mut id = String::with_capacity(MAX_LEN);
+    id.push_str(VERSION);
+    for field in core::iter::once(kind).chain(fields.iter().map(AsRef::as_ref)) {
+        if field.contains(SEPARATOR) {
+            return Err(Error::InvalidField);
+        }
+        id.push(SEPARATOR);
+        id.push_str(field);
+    }
+
+    if id.len() > MAX_LEN {
+        log::error!("Encoded custom ID is {} bytes long: {id}", id.len());
+        return Err(Error::TooLong);
+    }
+
+    Ok(id)
+}
+
+/// Decodes a `custom_id` into its `kind` and fields.
+pub fn decode(id: &str) -> Result<(&str, Vec<&str>), Error> {
+    if id.len() > MAX_LEN {
+        return Err(Error::TooLong);
+    }
+
+    let mut parts = id.split(SEPARATOR);
+    if parts.next() != Some(VERSION) {
+        return Err(Error::Version);
+    }
+
+    let kind = parts.next().filter(|kind| !kind.is_empty()).ok_or(Error::Malformed)?;
+    Ok((kind, parts.collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, MAX_LEN, decode, encode};
+
+    #[test]
+    fn round_trip() {
+        let id = encode("epoch", &["shift", "3600", "America/Argentina/ComodRivadavia"]).unwrap();
+        assert_eq!(id, "1|epoch|shift|3600|America/Argentina/ComodRivadavia");
+        assert_eq!(
+            decode(&id).unwrap(),
+            ("epoch", Vec::from(["shift", "3600", "America/Argentina/ComodRivadavia"]))
+        );
+        assert_eq!(decode("1|info").unwrap(), ("info", Vec::new()));
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        assert_eq!(encode("epoch", &["a|b"]), Err(Error::InvalidField));
+        assert_eq!(encode("epoch", &["x".repeat(MAX_LEN)]), Err(Error::TooLong));
+        assert_eq!(decode("0|epoch|shift"), Err(Error::Version));
+        assert_eq!(decode("1|"), Err(Error::Malformed));
+        assert_eq!(decode("epoch"), Err(Error::Version));
+    }
+}
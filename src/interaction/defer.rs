@@ -0,0 +1,349 @@
+//! Deferred responses for handlers which cannot answer within Discord's three-second window.
+//!
+//! A handler may explicitly return [`Reply::Deferred`] along with a future whose output is
+//! later delivered by editing the original response through the interaction webhook. In
+//! addition, [`run`] acts as a watchdog which defers the response of any handler that
+//! overruns the configured budget, delivering its eventual output in the same way. Since
+//! the acknowledgement fixes whether the response is a new message (and whether it is
+//! ephemeral), every route declares its [`Deferral`] up front.
+
+use super::error;
+use crate::{State, i18n::Catalog};
+use core::{future::Future, pin::Pin};
+use std::sync::Arc;
+use twilight_model::{
+    channel::message::MessageFlags,
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+    id::{Id, marker::ApplicationMarker},
+};
+
+/// How the response of a route is acknowledged when it is deferred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Deferral {
+    /// A new message which everyone in the channel can see.
+    Public,
+    /// A new message which only the invoker can see.
+    Ephemeral,
+    /// An update of the message to which the component is attached.
+    Update,
+}
+
+/// Work to be finished after the deferred response has been sent.
+pub type Task = Pin<Box<dyn Future<Output = error::Result<InteractionResponseData>> + Send>>;
+
+/// Outcome of a handler.
+#[expect(clippy::large_enum_variant, reason = "replies are only moved a handful of times")]
+pub enum Reply {
+    /// Respond immediately in the HTTP response.
+    Now(InteractionResponse),
+    /// Acknowledge now as given by the `deferral` and deliver the output of the `task` later.
+    #[cfg_attr(not(test), expect(dead_code, reason = "no handler opts into deferral yet"))]
+    Deferred { deferral: Deferral, task: Task },
+}
+
+impl From<InteractionResponse> for Reply {
+    fn from(response: InteractionResponse) -> Self {
+        Self::Now(response)
+    }
+}
+
+/// Identifies the interaction whose original response is to be edited later.
+pub struct Webhook {
+    /// How the response is deferred, which is absent for interactions that can never be
+    /// deferred (e.g., autocompletions, pings, and routes which may open a modal).
+    pub deferral: Option<Deferral>,
+    pub application: Id<ApplicationMarker>,
+    pub token: Box<str>,
+    /// Language in which errors are reported.
+    pub catalog: &'static Catalog,
+}
+
+/// Converts an error into an ephemeral message for the user.
+fn error_data(err: error::Error, catalog: &Catalog) -> InteractionResponseData {
+    InteractionResponseData {
+        content: Some(err.message(catalog)),
+        flags: Some(MessageFlags::EPHEMERAL),
+        ..Default::default()
+    }
+}
+
+fn error_response(err: error::Error, catalog: &Catalog) -> InteractionResponse {
+    InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(error_data(err, catalog)),
+    }
+}
+
+/// Creates the acknowledgement which defers the response as declared.
+fn acknowledge(deferral: Deferral) -> InteractionResponse {
+    use InteractionResponseType::{DeferredChannelMessageWithSource, DeferredUpdateMessage};
+    match deferral {
+        Deferral::Public => {
+            InteractionResponse { kind: DeferredChannelMessageWithSource, data: None }
+        }
+        Deferral::Ephemeral => InteractionResponse {
+            kind: DeferredChannelMessageWithSource,
+            data: Some(InteractionResponseData {
+                flags: Some(MessageFlags::EPHEMERAL),
+                ..Default::default()
+            }),
+        },
+        Deferral::Update => InteractionResponse { kind: DeferredUpdateMessage, data: None },
+    }
+}
+
+/// Resolves a (late) reply into the message data with which the original response is
+/// edited. Immediate responses must be of the kind that was declared upon deferral.
+async fn resolve(
+    reply: error::Result<Reply>,
+    deferral: Deferral,
+    catalog: &Catalog,
+) -> InteractionResponseData {
+    let expected = match deferral {
+        Deferral::Public | Deferral::Ephemeral => InteractionResponseType::ChannelMessageWithSource,
+        Deferral::Update => InteractionResponseType::UpdateMessage,
+    };
+    let result = match reply {
+        Ok(Reply::Now(InteractionResponse { kind, data })) if kind == expected => {
+            Ok(data.unwrap_or_default())
+        }
+        Ok(Reply::Now(InteractionResponse { kind, .. })) => {
+            log::error!("Cannot deliver a {kind:?} response after deferring as {deferral:?}.");
+            Err(error::Error::Fatal)
+        }
+        Ok(Reply::Deferred { task, .. }) => task.await,
+        Err(err) => Err(err),
+    };
+    if let Ok(data) = &result {
+        let is_ephemeral = data.flags.is_some_and(|flags| flags.contains(MessageFlags::EPHEMERAL));
+        if deferral != Deferral::Update && is_ephemeral != (deferral == Deferral::Ephemeral) {
+            log::warn!("Deferred a response as {deferral:?} despite its flags.");
+        }
+    }
+
+    let mut data = result.unwrap_or_else(|err| error_data(err, catalog));
+    // Ephemerality is fixed upon acknowledgement, so the flags may no longer be edited.
+    data.flags = None;
+    data
+}
+
+/// Edits the original response with the eventual output of the handler.
+async fn deliver(
+    state: &State,
+    webhook: &Webhook,
+    deferral: Deferral,
+    reply: error::Result<Reply>,
+) {
+    let data = resolve(reply, deferral, webhook.catalog).await;
+    match state.rest.update_original_with(webhook.application, &webhook.token, &data).await {
+        Ok(()) => log::info!("Delivered deferred response."),
+        Err(err) => log::error!("Failed to deliver deferred response: {err}."),
+    }
+}
+
+fn joined(result: Result<error::Result<Reply>, tokio::task::JoinError>) -> error::Result<Reply> {
+    result.unwrap_or_else(|err| {
+        log::error!("Handler did not run to completion: {err}.");
+        Err(error::Error::Fatal)
+    })
+}
+
+/// Runs the `handler` on the blocking thread pool. If it does not finish within the budget,
+/// the response is deferred and the output is delivered through the webhook once ready.
+pub async fn run<F>(state: &Arc<State>, webhook: Webhook, handler: F) -> InteractionResponse
+where
+    F: FnOnce(&State) -> error::Result<Reply> + Send + 'static,
+{
+    let inner = state.clone();
+    let mut handle = tokio::task::spawn_blocking(move || handler(&inner));
+    let reply = match tokio::time::timeout(state.budget, &mut handle).await {
+        Ok(result) => joined(result),
+        Err(_) => match webhook.deferral {
+            Some(deferral) => {
+                log::warn!("Handler exceeded its budget of {:?}. Deferring.", state.budget);
+                let state = state.clone();
+                tokio::spawn(async move {
+                    deliver(&state, &webhook, deferral, joined(handle.await)).await
+                });
+                return acknowledge(deferral);
+            }
+            None => {
+                log::warn!("Handler exceeded its budget of {:?}.", state.budget);
+                joined(handle.await)
+            }
+        },
+    };
+
+    match reply {
+        Ok(Reply::Now(response)) => response,
+        Ok(reply @ Reply::Deferred { deferral, .. }) => {
+            if webhook.deferral.is_none() {
+                log::error!("Attempted to defer an interaction which cannot be deferred.");
+                return error_response(error::Error::Fatal, webhook.catalog);
+            }
+            let state = state.clone();
+            tokio::spawn(async move { deliver(&state, &webhook, deferral, Ok(reply)).await });
+            acknowledge(deferral)
+        }
+        Err(err) => error_response(err, webhook.catalog),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Deferral, Reply, Webhook};
+    use crate::{State, job::SystemClock, rest, store::Store};
+    use core::time::Duration;
+    use std::sync::Arc;
+    use twilight_model::{
+        http::interaction::{
+            InteractionResponse, InteractionResponseData, InteractionResponseType,
+        },
+        id::Id,
+    };
+
+    fn state(mock: &rest::mock::Mock) -> Arc<State> {
+        Arc::new(State {
+            pub_key: ed25519_dalek::SigningKey::from_bytes(&[1; 32]).verifying_key(),
+            store: Store::in_memory(),
+            rest: rest::Client::new(mock.url(), None),
+            clock: Box::new(SystemClock),
+            budget: Duration::from_millis(50),
+            commands: None,
+            abbreviations: Default::default(),
+            learner: Default::default(),
+        })
+    }
+
+    fn webhook(deferral: Option<Deferral>) -> Webhook {
+        Webhook {
+            deferral,
+            application: Id::new(1),
+            token: "tok".into(),
+            catalog: &crate::i18n::EN,
+        }
+    }
+
+    fn message(content: &str) -> InteractionResponseData {
+        InteractionResponseData { content: Some(content.into()), ..Default::default() }
+    }
+
+    /// Waits until the mock has received the deferred response.
+    async fn delivered(mock: &rest::mock::Mock) -> serde_json::Value {
+        for _ in 0..100 {
+            if let Some(request) = mock.requests().pop() {
+                assert_eq!(request.method, "PATCH");
+                assert_eq!(request.path, "/webhooks/1/tok/messages/@original");
+                return serde_json::from_slice(&request.body).unwrap();
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("deferred response was never delivered");
+    }
+
+    #[tokio::test]
+    async fn fast_handlers_respond_immediately() {
+        let mock = rest::mock::Mock::start().await;
+        let res = super::run(&state(&mock), webhook(Some(Deferral::Ephemeral)), |_| {
+            Ok(Reply::Now(InteractionResponse {
+                kind: InteractionResponseType::ChannelMessageWithSource,
+                data: Some(message("hi")),
+            }))
+        })
+        .await;
+        assert_eq!(res.kind, InteractionResponseType::ChannelMessageWithSource);
+        assert_eq!(res.data.unwrap().content.as_deref(), Some("hi"));
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn explicit_deferral_posts_through_webhook() {
+        let mock = rest::mock::Mock::start().await;
+        let res = super::run(&state(&mock), webhook(Some(Deferral::Ephemeral)), |_| {
+            let task = Box::pin(async { Ok(message("done")) });
+            Ok(Reply::Deferred { deferral: Deferral::Public, task })
+        })
+        .await;
+        assert_eq!(res.kind, InteractionResponseType::DeferredChannelMessageWithSource);
+        assert!(res.data.is_none());
+        assert_eq!(delivered(&mock).await["content"], "done");
+
+        // Interactions which cannot be deferred report an error instead.
+        let mock = rest::mock::Mock::start().await;
+        let res = super::run(&state(&mock), webhook(None), |_| {
+            let task = Box::pin(async { Ok(message("done")) });
+            Ok(Reply::Deferred { deferral: Deferral::Public, task })
+        })
+        .await;
+        assert_eq!(res.kind, InteractionResponseType::ChannelMessageWithSource);
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn watchdog_defers_slow_handlers() {
+        let mock = rest::mock::Mock::start().await;
+        let res = super::run(&state(&mock), webhook(Some(Deferral::Ephemeral)), |_| {
+            std::thread::sleep(Duration::from_millis(200));
+            Err(super::error::Error::Fatal)
+        })
+        .await;
+        assert_eq!(res.kind, InteractionResponseType::DeferredChannelMessageWithSource);
+        assert!(res.data.unwrap().flags.is_some());
+
+        // Errors are still reported, albeit without the (no longer editable) flags.
+        let body = delivered(&mock).await;
+        assert_eq!(body["content"], super::error::Error::Fatal.message(&crate::i18n::EN));
+        assert!(body.get("flags").is_none());
+    }
+
+    #[tokio::test]
+    async fn watchdog_keeps_declared_visibility() {
+        let slow = |kind| {
+            move |_: &State| {
+                std::thread::sleep(Duration::from_millis(200));
+                Ok(Reply::Now(InteractionResponse { kind, data: Some(message("late")) }))
+            }
+        };
+
+        // Public replies stay public.
+        let mock = rest::mock::Mock::start().await;
+        let public = slow(InteractionResponseType::ChannelMessageWithSource);
+        let res = super::run(&state(&mock), webhook(Some(Deferral::Public)), public).await;
+        assert_eq!(res.kind, InteractionResponseType::DeferredChannelMessageWithSource);
+        assert!(res.data.is_none());
+        assert_eq!(delivered(&mock).await["content"], "late");
+
+        // Components which update their message defer as such.
+        let mock = rest::mock::Mock::start().await;
+        let update = slow(InteractionResponseType::UpdateMessage);
+        let res = super::run(&state(&mock), webhook(Some(Deferral::Update)), update).await;
+        assert_eq!(res.kind, InteractionResponseType::DeferredUpdateMessage);
+        assert_eq!(delivered(&mock).await["content"], "late");
+
+        // Responses of the wrong kind are reported instead.
+        let mock = rest::mock::Mock::start().await;
+        let update = slow(InteractionResponseType::UpdateMessage);
+        super::run(&state(&mock), webhook(Some(Deferral::Public)), update).await;
+        let body = delivered(&mock).await;
+        assert_eq!(body["content"], super::error::Error::Fatal.message(&crate::i18n::EN));
+
+        // Interactions which cannot be deferred simply wait.
+        let mock = rest::mock::Mock::start().await;
+        let public = slow(InteractionResponseType::ChannelMessageWithSource);
+        let res = super::run(&state(&mock), webhook(None), public).await;
+        assert_eq!(res.kind, InteractionResponseType::ChannelMessageWithSource);
+        assert!(mock.requests().is_empty());
+
+        // Hence, late modals still open whereas they could never be delivered once deferred.
+        let mock = rest::mock::Mock::start().await;
+        let modal = slow(InteractionResponseType::Modal);
+        let res = super::run(&state(&mock), webhook(None), modal).await;
+        assert_eq!(res.kind, InteractionResponseType::Modal);
+        assert!(mock.requests().is_empty());
+        let mock = rest::mock::Mock::start().await;
+        let modal = slow(InteractionResponseType::Modal);
+        super::run(&state(&mock), webhook(Some(Deferral::Ephemeral)), modal).await;
+        let body = delivered(&mock).await;
+        assert_eq!(body["content"], super::error::Error::Fatal.message(&crate::i18n::EN));
+    }
+}
@@ -1,33 +1,115 @@
-use core::fmt;
+use crate::i18n::{self, Catalog};
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Text inputs of modals whose values may be invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Date,
+    Time,
+    Timezone,
+}
+
+#[derive(Debug)]
 pub enum Error {
+    /// The abbreviation stands for different offsets in each of the candidate zones.
+    AmbiguousTimezone {
+        abbreviation: Box<str>,
+        candidates: Box<[Box<str>]>,
+    },
+    CannotPostPublicly,
     Fatal,
     InvalidArgs,
+    InvalidComponent,
+    InvalidDate,
+    InvalidDateTime,
+    InvalidField(Field),
+    /// The value of the named command option is invalid (e.g., not one of its choices).
+    InvalidOption(Box<str>),
+    InPast,
+    MissingChannel,
     MissingPayload,
     MissingRequired,
+    MissingOption(Box<str>),
     UnknownCommand,
+    /// The invoked command ID is not (or no longer) registered.
+    UnregisteredCommand,
     UnknownTimezone,
     UnsupportedInteractionType,
     OutOfRange,
+    OptionOutOfRange {
+        option: Box<str>,
+        min: i64,
+        max: i64,
+    },
+    Storage,
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            Self::Fatal => {
-                "Unrecoverable error. This is unexpected behavior. Please file a bug report."
+impl Error {
+    /// Renders the error as a user-facing message in the language of the `catalog`.
+    pub fn message(&self, catalog: &Catalog) -> String {
+        let errors = &catalog.errors;
+        String::from(match self {
+            Self::InvalidField(field) => {
+                let input = match field {
+                    Field::Date => &catalog.epoch.date,
+                    Field::Time => &catalog.epoch.time,
+                    Field::Timezone => &catalog.epoch.timezone,
+                };
+                return i18n::fill(
+                    errors.invalid_field,
+                    &[("field", input.label), ("hint", input.hint)],
+                );
+            }
+            Self::InvalidOption(option) => {
+                return i18n::fill(errors.invalid_option, &[("option", option)]);
+            }
+            Self::MissingOption(option) => {
+                return i18n::fill(errors.missing_option, &[("option", option)]);
+            }
+            Self::OptionOutOfRange { option, min, max } => {
+                let (min, max) = (min.to_string(), max.to_string());
+                return i18n::fill(
+                    errors.option_out_of_range,
+                    &[("option", option), ("min", &min), ("max", &max)],
+                );
             }
-            Self::InvalidArgs => "Invalid command arguments.",
-            Self::MissingPayload => "No interaction data present.",
-            Self::MissingRequired => "Required arguments not provided.",
-            Self::UnknownCommand => "Unknown command name.",
-            Self::UnknownTimezone => {
-                "Unknown timezone. Please ensure that it is in the IANA Time Zone Database."
+            Self::AmbiguousTimezone { abbreviation, candidates } => {
+                let candidates: Vec<_> =
+                    candidates.iter().map(|candidate| format!("- {candidate}")).collect();
+                return i18n::fill(
+                    errors.ambiguous_timezone,
+                    &[("abbreviation", abbreviation), ("candidates", &candidates.join("\n"))],
+                );
             }
-            Self::UnsupportedInteractionType => "Unsupported interaction type.",
-            Self::OutOfRange => "A value is out of range. It is either too large or too small.",
+            Self::CannotPostPublicly => errors.cannot_post_publicly,
+            Self::Fatal => errors.fatal,
+            Self::InvalidArgs => errors.invalid_args,
+            Self::InvalidComponent => errors.invalid_component,
+            Self::InvalidDate => errors.invalid_date,
+            Self::InvalidDateTime => errors.invalid_date_time,
+            Self::InPast => errors.in_past,
+            Self::MissingChannel => errors.missing_channel,
+            Self::MissingPayload => errors.missing_payload,
+            Self::MissingRequired => errors.missing_required,
+            Self::UnknownCommand => errors.unknown_command,
+            Self::UnregisteredCommand => errors.unregistered_command,
+            Self::UnknownTimezone => errors.unknown_timezone,
+            Self::UnsupportedInteractionType => errors.unsupported_interaction_type,
+            Self::OutOfRange => errors.out_of_range,
+            Self::Storage => errors.storage,
         })
     }
 }
+
+impl From<crate::util::tz::Unresolved> for Error {
+    fn from(unresolved: crate::util::tz::Unresolved) -> Self {
+        use crate::util::tz::Unresolved;
+        match unresolved {
+            Unresolved::Unknown => Self::UnknownTimezone,
+            Unresolved::Ambiguous { abbreviation, candidates } => {
+                Self::AmbiguousTimezone { abbreviation, candidates }
+            }
+        }
+    }
+}
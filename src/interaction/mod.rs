@@ -1,75 +1,162 @@
+mod browse;
 mod command;
+mod custom_id;
+mod defer;
 mod embed;
 mod error;
+mod share;
 
+use crate::{
+    State,
+    i18n::{self, Catalog},
+};
+use defer::{Deferral, Reply};
+use std::sync::Arc;
 use twilight_model::{
-    application::interaction::{Interaction, application_command::CommandData},
-    http::interaction::{InteractionResponse, InteractionResponseType},
+    application::interaction::{
+        Interaction, application_command::CommandData,
+        message_component::MessageComponentInteractionData, modal::ModalInteractionData,
+    },
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
 
-/// Router for the various command handlers.
-fn on_app_command(data: CommandData) -> error::Result<InteractionResponse> {
-    // TODO: Verify command ID.
-    Ok(InteractionResponse {
-        kind: InteractionResponseType::ChannelMessageWithSource,
-        data: Some(match data.name.as_str() {
-            "epoch" => command::epoch::execute(data)?,
-            "help" => command::help::execute(data).ok_or(error::Error::UnknownCommand)?,
-            "info" => command::info::execute(),
-            other => {
-                log::error!("Invoked unknown /{other} command.");
-                return Err(error::Error::UnknownCommand);
-            }
-        }),
-    })
+/// Handler of a message component given the fields decoded from its `custom_id` and the
+/// values selected (if any).
+type ComponentHandler =
+    fn(&Interaction, &[&str], &[String], &State, &Catalog) -> error::Result<InteractionResponse>;
+
+/// Handler of a modal given the fields decoded from its `custom_id` and the pairs of each
+/// text input's `custom_id` and its submitted value.
+type ModalHandler = fn(
+    &Interaction,
+    &[&str],
+    &[(String, String)],
+    &State,
+    &Catalog,
+) -> error::Result<InteractionResponse>;
+
+/// Wraps the message `data` as an update of the message to which the component is attached.
+fn update(data: InteractionResponseData) -> InteractionResponse {
+    InteractionResponse { kind: InteractionResponseType::UpdateMessage, data: Some(data) }
 }
 
-fn on_autocomplete(data: CommandData) -> Option<InteractionResponse> {
-    use twilight_model::{
-        application::{
-            command::{CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType},
-            interaction::application_command::{CommandDataOption, CommandOptionValue::Focused},
-        },
-        http::interaction::{
-            InteractionResponseData, InteractionResponseType::ApplicationCommandAutocompleteResult,
-        },
+/// Component handlers keyed by the kind encoded in their `custom_id` along with how their
+/// responses are deferred.
+static COMPONENTS: [(&str, Option<Deferral>, ComponentHandler); 3] = [
+    ("browse", Some(Deferral::Update), |_, fields, values, state, _| {
+        browse::on_component(fields, values, state.clock.now()).map(update)
+    }),
+    ("epoch", Some(Deferral::Update), |_, fields, values, _, catalog| {
+        command::epoch::on_component(fields, values, catalog).map(update)
+    }),
+    // Unlike the others, this posts a brand new message instead of updating the original.
+    ("share", Some(Deferral::Public), |interaction, fields, _, _, catalog| {
+        share::on_component(interaction, fields, catalog)
+    }),
+];
+
+/// Modal handlers keyed by the kind encoded in their `custom_id` along with how their
+/// responses are deferred.
+static MODALS: [(&str, Option<Deferral>, ModalHandler); 1] =
+    [("epoch-modal", Some(Deferral::Ephemeral), |interaction, fields, inputs, state, catalog| {
+        let (data, zone) = command::epoch::on_modal(fields, inputs, catalog)?;
+        command::zones::remember(interaction, state, &zone);
+        Ok(InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        })
+    })];
+
+/// Router for the various command handlers, which may explicitly defer their response.
+fn on_app_command(
+    interaction: &Interaction,
+    data: CommandData,
+    state: &State,
+    catalog: &Catalog,
+) -> error::Result<Reply> {
+    command::execute(interaction, data, state, catalog)
+}
+
+/// The registered definitions of every command.
+pub fn commands() -> Vec<twilight_model::application::command::Command> {
+    command::definitions().cloned().collect()
+}
+
+/// Router for the message components, which are dispatched by the kind
+/// encoded in their `custom_id`.
+fn on_component(
+    interaction: &Interaction,
+    data: MessageComponentInteractionData,
+    state: &State,
+    catalog: &Catalog,
+) -> error::Result<InteractionResponse> {
+    let MessageComponentInteractionData { custom_id, values, .. } = data;
+    let (kind, fields) = custom_id::decode(&custom_id).map_err(|err| {
+        log::error!("Failed to decode custom ID {custom_id}: {err:?}.");
+        error::Error::InvalidComponent
+    })?;
+
+    let Some((_, _, handler)) = COMPONENTS.iter().find(|(other, ..)| *other == kind) else {
+        log::error!("Interacted with unknown {kind} component.");
+        return Err(error::Error::InvalidComponent);
     };
+    handler(interaction, &fields, &values, state, catalog)
+}
 
-    if data.name.as_str() != "epoch" {
-        return None;
+/// Router for the modal submissions, which are dispatched by the kind
+/// encoded in their `custom_id`.
+fn on_modal_submit(
+    interaction: &Interaction,
+    data: ModalInteractionData,
+    state: &State,
+    catalog: &Catalog,
+) -> error::Result<InteractionResponse> {
+    use twilight_model::application::interaction::modal::ModalInteractionComponent;
+
+    /// Flattens the (possibly nested) text inputs into `(custom_id, value)` pairs.
+    fn collect(components: Vec<ModalInteractionComponent>, inputs: &mut Vec<(String, String)>) {
+        for component in components {
+            match component {
+                ModalInteractionComponent::ActionRow(row) => collect(row.components, inputs),
+                ModalInteractionComponent::Label(label) => {
+                    collect(Vec::from([*label.component]), inputs)
+                }
+                ModalInteractionComponent::TextInput(input) => {
+                    inputs.push((input.custom_id, input.value))
+                }
+                _ => {}
+            }
+        }
     }
 
-    let choices: Vec<_> = data
-        .options
-        .into_iter()
-        .find_map(|CommandDataOption { name, value }| match (name.as_str(), value) {
-            ("timezone", Focused(comm, CommandOptionType::String)) => Some(comm.into_boxed_str()),
-            _ => None,
-        })
-        .map(|query| crate::util::autocomplete_tz(&query, 25))
-        .unwrap_or_default()
-        .into_vec() // TODO: Remove this intermediate step in Edition 2024.
-        .into_iter()
-        .take(25)
-        .map(|tz| CommandOptionChoice {
-            name: tz.replace('_', " "),
-            name_localizations: None,
-            value: CommandOptionChoiceValue::String(String::from(tz.as_ref())),
-        })
-        .collect();
+    let ModalInteractionData { custom_id, components, .. } = data;
+    let (kind, fields) = custom_id::decode(&custom_id).map_err(|err| {
+        log::error!("Failed to decode custom ID {custom_id}: {err:?}.");
+        error::Error::InvalidComponent
+    })?;
+
+    let Some((_, _, handler)) = MODALS.iter().find(|(other, ..)| *other == kind) else {
+        log::error!("Submitted unknown {kind} modal.");
+        return Err(error::Error::InvalidComponent);
+    };
 
-    log::info!("Generated {} autocompletions", choices.len());
-    Some(InteractionResponse {
-        kind: ApplicationCommandAutocompleteResult,
-        data: Some(InteractionResponseData { choices: Some(choices), ..Default::default() }),
-    })
+    let mut inputs = Vec::new();
+    collect(components, &mut inputs);
+    handler(interaction, &fields, &inputs, state, catalog)
 }
 
-fn try_respond(interaction: Interaction) -> error::Result<InteractionResponse> {
+fn try_respond(
+    mut interaction: Interaction,
+    state: &State,
+    catalog: &Catalog,
+) -> error::Result<Reply> {
     use twilight_model::{
         application::interaction::{
             InteractionData,
-            InteractionType::{ApplicationCommand, ApplicationCommandAutocomplete, Ping},
+            InteractionType::{
+                ApplicationCommand, ApplicationCommandAutocomplete, MessageComponent, ModalSubmit,
+                Ping,
+            },
         },
         http::interaction::InteractionResponseType::Pong,
     };
@@ -77,9 +164,29 @@ fn try_respond(interaction: Interaction) -> error::Result<InteractionResponse> {
     let is_comm = match interaction.kind {
         ApplicationCommand => true,
         ApplicationCommandAutocomplete => false,
+        ModalSubmit => {
+            let InteractionData::ModalSubmit(data) =
+                interaction.data.take().ok_or(error::Error::MissingPayload)?
+            else {
+                log::error!("Missing payload from modal submission.");
+                return Err(error::Error::Fatal);
+            };
+            log::info!("Received modal submission.");
+            return on_modal_submit(&interaction, *data, state, catalog).map(Reply::Now);
+        }
+        MessageComponent => {
+            let InteractionData::MessageComponent(data) =
+                interaction.data.take().ok_or(error::Error::MissingPayload)?
+            else {
+                log::error!("Missing payload from message component interaction.");
+                return Err(error::Error::Fatal);
+            };
+            log::info!("Received message component interaction.");
+            return on_component(&interaction, *data, state, catalog).map(Reply::Now);
+        }
         Ping => {
             log::info!("Received a ping.");
-            return Ok(InteractionResponse { kind: Pong, data: None });
+            return Ok(Reply::Now(InteractionResponse { kind: Pong, data: None }));
         }
         other => {
             log::error!("Received unsupported interaction type {other:?}.");
@@ -87,7 +194,7 @@ fn try_respond(interaction: Interaction) -> error::Result<InteractionResponse> {
         }
     };
 
-    let data = match interaction.data.ok_or(error::Error::MissingPayload)? {
+    let data = match interaction.data.take().ok_or(error::Error::MissingPayload)? {
         InteractionData::ApplicationCommand(data) => *data,
         _ => {
             log::error!("Missing payload from application command invocation.");
@@ -97,29 +204,46 @@ fn try_respond(interaction: Interaction) -> error::Result<InteractionResponse> {
 
     if is_comm {
         log::info!("Received application command.");
-        on_app_command(data)
+        on_app_command(&interaction, data, state, catalog)
     } else {
         log::info!("Received autocompletion request.");
-        on_autocomplete(data).ok_or(error::Error::UnknownCommand)
+        command::autocomplete::respond(&interaction, &data, state, catalog)
+            .map(Reply::Now)
+            .ok_or(error::Error::UnknownCommand)
     }
 }
 
-pub fn respond(interaction: Interaction) -> InteractionResponse {
-    try_respond(interaction).unwrap_or_else(|err| {
-        use std::string::ToString;
-        use twilight_model::{
-            channel::message::MessageFlags,
-            http::interaction::{
-                InteractionResponseData, InteractionResponseType::ChannelMessageWithSource,
-            },
-        };
-        InteractionResponse {
-            kind: ChannelMessageWithSource,
-            data: Some(InteractionResponseData {
-                content: Some(err.to_string()),
-                flags: Some(MessageFlags::EPHEMERAL),
-                ..Default::default()
-            }),
+/// How the response to the `interaction` is deferred (if at all) as declared by its route.
+/// Unknown routes are never deferred since their handlers fail fast anyway.
+fn deferral(interaction: &Interaction) -> Option<Deferral> {
+    use twilight_model::application::interaction::{
+        InteractionData, InteractionType::ApplicationCommand,
+    };
+
+    fn find<H>(routes: &[(&str, Option<Deferral>, H)], custom_id: &str) -> Option<Deferral> {
+        let (kind, _) = custom_id::decode(custom_id).ok()?;
+        routes.iter().find(|(other, ..)| *other == kind).and_then(|(_, deferral, _)| *deferral)
+    }
+
+    match interaction.data.as_ref()? {
+        InteractionData::ApplicationCommand(data) if interaction.kind == ApplicationCommand => {
+            command::deferral(data)
         }
-    })
+        InteractionData::MessageComponent(data) => find(&COMPONENTS, &data.custom_id),
+        InteractionData::ModalSubmit(data) => find(&MODALS, &data.custom_id),
+        _ => None,
+    }
+}
+
+/// Responds to the `interaction` in the user's language, deferring the response if the
+/// handler is too slow.
+pub async fn respond(interaction: Interaction, state: &Arc<State>) -> InteractionResponse {
+    let catalog = i18n::select(interaction.locale.as_deref(), interaction.guild_locale.as_deref());
+    let webhook = defer::Webhook {
+        deferral: deferral(&interaction),
+        application: interaction.application_id,
+        token: interaction.token.as_str().into(),
+        catalog,
+    };
+    defer::run(state, webhook, move |state| try_respond(interaction, state, catalog)).await
 }
@@ -0,0 +1,150 @@
+//! "Post publicly" button for reposting an (ephemeral) timestamp to the whole channel.
+
+use super::{custom_id, error};
+use crate::i18n::{self, Catalog};
+use twilight_model::{
+    application::interaction::{Interaction, InteractionContextType},
+    channel::message::{
+        AllowedMentions, Component,
+        component::{Button, ButtonStyle},
+    },
+    guild::Permissions,
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+};
+
+/// Creates a button which publicly posts the `timestamp` in the given `style`.
+pub fn button(timestamp: i64, style: char, catalog: &Catalog) -> error::Result<Component> {
+    let custom_id = custom_id::encode("share", &[timestamp.to_string(), style.to_string()])
+        .map_err(|err| {
+            log::error!("Failed to encode custom ID: {err:?}.");
+            error::Error::Fatal
+        })?;
+    Ok(Component::Button(Button {
+        id: None,
+        custom_id: Some(custom_id),
+        disabled: false,
+        emoji: None,
+        label: Some(String::from(catalog.epoch.share)),
+        style: ButtonStyle::Success,
+        url: None,
+        sku_id: None,
+    }))
+}
+
+/// Checks whether a public response is permitted in the context of the `interaction`.
+/// In guilds, the app must be able to send messages. Direct messages and group chats
+/// are always permitted since only the invoking user could have added the app there.
+fn can_post(interaction: &Interaction) -> bool {
+    match interaction.context {
+        Some(InteractionContextType::Guild) | None => interaction
+            .app_permissions
+            .is_none_or(|perms| perms.contains(Permissions::SEND_MESSAGES)),
+        Some(InteractionContextType::BotDm | InteractionContextType::PrivateChannel) => true,
+        Some(other) => {
+            log::warn!("Refusing to post publicly in unknown context {other:?}.");
+            false
+        }
+    }
+}
+
+/// Handler for the "Post publicly" button. Unlike most component handlers, this responds
+/// with a brand new (non-ephemeral) message instead of updating the original.
+pub fn on_component(
+    interaction: &Interaction,
+    fields: &[&str],
+    catalog: &Catalog,
+) -> error::Result<InteractionResponse> {
+    let [timestamp, style] = fields else {
+        log::error!("Unexpected share component fields {fields:?}.");
+        return Err(error::Error::InvalidComponent);
+    };
+
+    let timestamp: i64 = timestamp.parse().map_err(|_| error::Error::InvalidComponent)?;
+    if !matches!(*style, "t" | "T" | "d" | "D" | "f" | "F" | "R") {
+        log::error!("Unknown timestamp style {style}.");
+        return Err(error::Error::InvalidComponent);
+    }
+
+    if !can_post(interaction) {
+        return Err(error::Error::CannotPostPublicly);
+    }
+
+    let user = interaction.author_id().ok_or(error::Error::MissingPayload)?;
+    Ok(InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(InteractionResponseData {
+            content: Some(i18n::fill(
+                catalog.epoch.shared,
+                &[
+                    ("timestamp", &format!("<t:{timestamp}:{style}>")),
+                    ("user", &format!("<@{user}>")),
+                ],
+            )),
+            // Attribute the message without pinging the user.
+            allowed_mentions: Some(AllowedMentions::default()),
+            ..Default::default()
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::i18n::EN;
+    use twilight_model::{
+        application::interaction::{Interaction, InteractionContextType},
+        guild::Permissions,
+    };
+
+    fn interaction(context: InteractionContextType, perms: Permissions) -> Interaction {
+        serde_json::from_value(serde_json::json!({
+            "app_permissions": perms.bits().to_string(),
+            "application_id": "1",
+            "authorizing_integration_owners": { "1": "5" },
+            "context": u8::from(context),
+            "data": { "custom_id": "1|share|0|R", "component_type": 2 },
+            "entitlements": [],
+            "id": "2",
+            "token": "tok",
+            "type": 3,
+            "user": {
+                "avatar": null,
+                "discriminator": "0",
+                "id": "5",
+                "username": "someone",
+            },
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn respects_permissions_and_context() {
+        let all = Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL;
+        let res =
+            super::on_component(&interaction(InteractionContextType::Guild, all), &["0", "R"], &EN)
+                .unwrap();
+        let data = res.data.unwrap();
+        assert_eq!(data.content.as_deref(), Some("<t:0:R> (shared by <@5>)"));
+        assert!(data.flags.is_none());
+
+        // The attribution follows the locale of the user who shares it.
+        let es = crate::i18n::select(Some("es-ES"), None);
+        let res =
+            super::on_component(&interaction(InteractionContextType::Guild, all), &["0", "R"], es)
+                .unwrap();
+        assert_eq!(res.data.unwrap().content.as_deref(), Some("<t:0:R> (compartido por <@5>)"));
+
+        let muted = Permissions::VIEW_CHANNEL;
+        let err = super::on_component(
+            &interaction(InteractionContextType::Guild, muted),
+            &["0", "R"],
+            &EN,
+        )
+        .unwrap_err();
+        assert!(matches!(err, super::error::Error::CannotPostPublicly));
+
+        let dm = interaction(InteractionContextType::PrivateChannel, muted);
+        assert!(super::on_component(&dm, &["0", "R"], &EN).is_ok());
+        assert!(super::on_component(&dm, &["0", "X"], &EN).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{rest, store::Store};
+use jiff::Timestamp;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, UserMarker},
+};
+
+/// How often the birthdays are re-evaluated. This is much shorter than a day so that
+/// announcements go out shortly after midnight in every timezone (including those
+/// with sub-hour offsets).
+pub const PERIOD: core::time::Duration = core::time::Duration::from_secs(15 * 60);
+
+/// A birthday which is due for announcement.
+struct Due {
+    user: Id<UserMarker>,
+    channel: Id<ChannelMarker>,
+    year: i16,
+}
+
+/// Collects every birthday whose date has begun in the user's own timezone
+/// but which has not yet been announced for the current (local) year.
+fn collect_due(store: &Store, now: Timestamp) -> Vec<Due> {
+    store.read(|db| {
+        db.birthdays
+            .iter()
+            .filter_map(|birthday| {
+                let tz = match crate::util::tz::resolve(&birthday.zone) {
+                    Ok(zone) => zone.tz,
+                    Err(err) => {
+                        log::error!(
+                            "Skipping birthday in unknown zone {}: {err:?}.",
+                            birthday.zone
+                        );
+                        return None;
+                    }
+                };
+                let today = now.to_zoned(tz).date();
+                let year = today.year();
+                if birthday.occurrence(year) != today || birthday.announced == Some(year) {
+                    return None;
+                }
+                Some(Due { user: birthday.user, channel: birthday.channel, year })
+            })
+            .collect()
+    })
+}
+
+/// Announces every birthday that has begun at `now`. Successful announcements are
+/// recorded so that each birthday is announced at most once per year.
+pub async fn tick(store: &Store, client: &rest::Client, now: Timestamp) {
+    for Due { user, channel, year } in collect_due(store, now) {
+        let content = format!("🎂 Happy birthday, <@{user}>! 🎉");
+        if let Err(err) = client.create_message(channel, &content).await {
+            log::error!("Failed to announce birthday of {user} in channel {channel}: {err}.");
+            continue;
+        }
+
+        let result = store.write(|db| {
+            if let Some(birthday) = db
+                .birthdays
+                .iter_mut()
+                .find(|birthday| birthday.user == user && birthday.channel == channel)
+            {
+                birthday.announced = Some(year);
+            }
+        });
+        match result {
+            Ok(()) => log::info!("Announced birthday of {user} in channel {channel}."),
+            Err(err) => log::error!("Failed to persist birthday state: {err}."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        job::{Clock, FakeClock},
+        rest::{self, mock::Mock},
+        store::{Birthday, LeapFallback, Store},
+    };
+    use twilight_model::id::Id;
+
+    fn store_with(birthdays: impl IntoIterator<Item = Birthday>) -> Store {
+        let store = Store::in_memory();
+        store.write(|db| db.birthdays.extend(birthdays)).unwrap();
+        store
+    }
+
+    fn birthday(user: u64, month: i8, day: i8, zone: &str, fallback: LeapFallback) -> Birthday {
+        Birthday {
+            user: Id::new(user),
+            channel: Id::new(99),
+            month,
+            day,
+            zone: zone.into(),
+            fallback,
+            announced: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn announces_at_local_midnight() {
+        let mock = Mock::start().await;
+        let client = rest::Client::new(mock.url(), Some("secret".into()));
+        let store = store_with([
+            birthday(1, 6, 15, "Asia/Manila", LeapFallback::Feb28),
+            birthday(2, 6, 15, "America/Los_Angeles", LeapFallback::Feb28),
+        ]);
+
+        // Still June 14 everywhere.
+        let clock = FakeClock::new("2025-06-14T15:00:00Z".parse().unwrap());
+        super::tick(&store, &client, clock.now()).await;
+        assert!(mock.requests().is_empty());
+
+        // Midnight of June 15 in Manila, but still June 14 in Los Angeles.
+        clock.set("2025-06-14T16:05:00Z".parse().unwrap());
+        super::tick(&store, &client, clock.now()).await;
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/channels/99/messages");
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert!(body["content"].as_str().unwrap().contains("<@1>"));
+
+        // Midnight of June 15 in Los Angeles. Manila must not be announced twice.
+        clock.set("2025-06-15T07:05:00Z".parse().unwrap());
+        super::tick(&store, &client, clock.now()).await;
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert!(body["content"].as_str().unwrap().contains("<@2>"));
+    }
+
+    #[tokio::test]
+    async fn leap_day_fallback() {
+        let mock = Mock::start().await;
+        let client = rest::Client::new(mock.url(), Some("secret".into()));
+        let store = store_with([
+            birthday(1, 2, 29, "UTC", LeapFallback::Feb28),
+            birthday(2, 2, 29, "UTC", LeapFallback::Mar1),
+        ]);
+
+        super::tick(&store, &client, "2025-02-28T12:00:00Z".parse().unwrap()).await;
+        assert_eq!(mock.requests().len(), 1);
+        super::tick(&store, &client, "2025-03-01T12:00:00Z".parse().unwrap()).await;
+        assert_eq!(mock.requests().len(), 2);
+
+        // Both are celebrated on the actual day in leap years.
+        super::tick(&store, &client, "2028-02-29T12:00:00Z".parse().unwrap()).await;
+        assert_eq!(mock.requests().len(), 4);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    rest,
+    store::{Countdown, Store},
+};
+use jiff::{SignedDuration, Timestamp};
+
+/// How often the countdown messages are edited.
+pub const PERIOD: core::time::Duration = core::time::Duration::from_secs(60);
+
+/// Interaction tokens are valid for 15 minutes. We switch to bot-token edits slightly
+/// earlier so that an in-flight edit never races the expiry.
+const TOKEN_LIFETIME: SignedDuration = SignedDuration::from_mins(14);
+
+/// Renders the message content of a countdown at `now`.
+pub fn render(title: Option<&str>, target: Timestamp, now: Timestamp) -> String {
+    let secs = target.as_second();
+    let header = title.map(|title| format!("**{title}**\n")).unwrap_or_default();
+    if now >= target {
+        return format!("{header}🎉 It's time! (<t:{secs}:F>)");
+    }
+
+    let remaining = target.as_second() - now.as_second();
+    let (days, hours, minutes) = (remaining / 86400, remaining / 3600 % 24, remaining / 60 % 60);
+    format!(
+        "{header}⏳ {days} day(s), {hours} hour(s), and {minutes} minute(s) remaining until <t:{secs}:F> (<t:{secs}:R>)."
+    )
+}
+
+/// Edits every active countdown with the time remaining at `now`. Countdowns which
+/// have reached their target receive one final edit and are then removed.
+pub async fn tick(store: &Store, client: &rest::Client, now: Timestamp) {
+    let countdowns = store.read(|db| db.countdowns.clone());
+    for countdown in countdowns {
+        let content = render(countdown.title.as_deref(), countdown.target, now);
+        let is_expired = now >= countdown.created + TOKEN_LIFETIME;
+        let location = countdown.channel.zip(countdown.message);
+        let result = match (is_expired, location) {
+            (false, _) => client
+                .update_original(countdown.application, &countdown.token, &content)
+                .await
+                .map(Some),
+            (true, Some((channel, message))) => {
+                client.update_message(channel, message, &content).await.map(|()| None)
+            }
+            (true, None) => {
+                // Without a known location, there is no way to ever edit the message again.
+                log::error!("Interaction token expired before the countdown message was located.");
+                Ok(None)
+            }
+        };
+
+        let learned = match result {
+            Ok(learned) => learned,
+            Err(err) => {
+                log::error!("Failed to edit countdown message: {err}.");
+                continue;
+            }
+        };
+
+        let is_done = now >= countdown.target || is_expired && location.is_none();
+        let is_same = |other: &Countdown| {
+            other.application == countdown.application && other.token == countdown.token
+        };
+        let result = store.write(|db| {
+            if is_done {
+                db.countdowns.retain(|other| !is_same(other));
+            } else if let Some(rest::MessageRef { id, channel_id }) = learned
+                && let Some(other) = db.countdowns.iter_mut().find(|other| is_same(other))
+            {
+                other.channel = Some(channel_id);
+                other.message = Some(id);
+            }
+        });
+        if let Err(err) = result {
+            log::error!("Failed to persist countdown state: {err}.");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        job::{Clock, FakeClock},
+        rest::{self, mock::Mock},
+        store::{Countdown, Store},
+    };
+    use jiff::{SignedDuration, Timestamp};
+    use twilight_model::id::Id;
+
+    #[test]
+    fn render_remaining() {
+        let target: Timestamp = "2025-01-01T00:00:00Z".parse().unwrap();
+        let now = target - SignedDuration::from_secs(2 * 86400 + 3 * 3600 + 4 * 60 + 5);
+        let content = super::render(Some("New Year"), target, now);
+        assert!(content.starts_with("**New Year**\n"));
+        assert!(content.contains("2 day(s), 3 hour(s), and 4 minute(s)"));
+        assert!(content.contains("<t:1735689600:R>"));
+        assert!(super::render(None, target, target).starts_with("🎉 It's time!"));
+    }
+
+    #[tokio::test]
+    async fn switches_to_bot_token_after_expiry() {
+        let mock = Mock::start().await;
+        mock.reply_with(r#"{"id":"500","channel_id":"600"}"#);
+        let client = rest::Client::new(mock.url(), Some("secret".into()));
+
+        let created: Timestamp = "2025-01-01T00:00:00Z".parse().unwrap();
+        let target = created + SignedDuration::from_hours(1);
+        let store = Store::in_memory();
+        store
+            .write(|db| {
+                db.countdowns.push(Countdown {
+                    application: Id::new(1),
+                    token: "tok".into(),
+                    created,
+                    target,
+                    title: None,
+                    channel: None,
+                    message: None,
+                })
+            })
+            .unwrap();
+
+        // Within the token lifetime: edit through the interaction webhook.
+        let clock = FakeClock::new(created + SignedDuration::from_mins(1));
+        super::tick(&store, &client, clock.now()).await;
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "PATCH");
+        assert_eq!(requests[0].path, "/webhooks/1/tok/messages/@original");
+        assert_eq!(requests[0].authorization, None);
+
+        // After the token expires: edit the channel message with the bot token.
+        clock.set(created + SignedDuration::from_mins(20));
+        super::tick(&store, &client, clock.now()).await;
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "PATCH");
+        assert_eq!(requests[1].path, "/channels/600/messages/500");
+        assert_eq!(requests[1].authorization.as_deref(), Some("Bot secret"));
+
+        // The final edit removes the countdown.
+        clock.set(target);
+        super::tick(&store, &client, clock.now()).await;
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        let body: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+        assert!(body["content"].as_str().unwrap().contains("It's time!"));
+        assert!(store.read(|db| db.countdowns.is_empty()));
+
+        clock.set(target + SignedDuration::from_mins(1));
+        super::tick(&store, &client, clock.now()).await;
+        assert_eq!(mock.requests().len(), 3);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{rest, store::Store};
+use jiff::{SignedDuration, Timestamp, tz::TimeZone};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+/// How often the subscriptions are re-evaluated.
+pub const PERIOD: core::time::Duration = core::time::Duration::from_secs(15 * 60);
+
+/// An alert which is due for posting.
+struct Due {
+    channel: Id<ChannelMarker>,
+    zone: Box<str>,
+    transition: Timestamp,
+    content: String,
+}
+
+/// Renders the warning message for the transition of `tz` at `at`.
+pub fn describe(zone: &str, tz: &TimeZone, at: Timestamp) -> String {
+    use crate::util::format_offset;
+    let before = tz.to_offset_info(at - SignedDuration::from_secs(1));
+    let after = tz.to_offset_info(at);
+    let secs = at.as_second();
+    format!(
+        "⏰ Heads up! **{zone}** switches from {} ({}) to {} ({}) on <t:{secs}:F> (<t:{secs}:R>).",
+        before.abbreviation(),
+        format_offset(before.offset()),
+        after.abbreviation(),
+        format_offset(after.offset()),
+    )
+}
+
+/// Returns the first transition of `tz` strictly after `now`, if any.
+pub fn next_transition(tz: &TimeZone, now: Timestamp) -> Option<Timestamp> {
+    tz.following(now).next().map(|transition| transition.timestamp())
+}
+
+/// Collects every subscription whose upcoming transition is within its lead time
+/// and has not yet been announced.
+fn collect_due(store: &Store, now: Timestamp) -> Vec<Due> {
+    store.read(|db| {
+        db.dst_alerts
+            .iter()
+            .filter_map(|alert| {
+                let tz = match crate::util::tz::resolve(&alert.zone) {
+                    Ok(zone) => zone.tz,
+                    Err(err) => {
+                        log::error!(
+                            "Skipping subscription for unknown zone {}: {err:?}.",
+                            alert.zone
+                        );
+                        return None;
+                    }
+                };
+                let transition = next_transition(&tz, now)?;
+                let lead = SignedDuration::from_hours(24 * i64::from(alert.lead));
+                if transition - lead > now || alert.notified == Some(transition) {
+                    return None;
+                }
+                Some(Due {
+                    channel: alert.channel,
+                    content: describe(&alert.zone, &tz, transition),
+                    zone: alert.zone.clone(),
+                    transition,
+                })
+            })
+            .collect()
+    })
+}
+
+/// Posts every alert that has become due at `now`. Successfully posted alerts are
+/// marked so that each transition is announced at most once per subscription.
+pub async fn tick(store: &Store, client: &rest::Client, now: Timestamp) {
+    for Due { channel, zone, transition, content } in collect_due(store, now) {
+        if let Err(err) = client.create_message(channel, &content).await {
+            log::error!("Failed to post DST alert for {zone} to channel {channel}: {err}.");
+            continue;
+        }
+
+        let result = store.write(|db| {
+            if let Some(alert) = db
+                .dst_alerts
+                .iter_mut()
+                .find(|alert| alert.channel == channel && alert.zone == zone)
+            {
+                alert.notified = Some(transition);
+            }
+        });
+        match result {
+            Ok(()) => log::info!("Posted DST alert for {zone} to channel {channel}."),
+            Err(err) => log::error!("Failed to persist DST alert state: {err}."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        job::{Clock, FakeClock},
+        rest::{self, mock::Mock},
+        store::{DstAlert, Store},
+    };
+    use twilight_model::id::Id;
+
+    #[tokio::test]
+    async fn posts_once_within_lead_time() {
+        let mock = Mock::start().await;
+        let client = rest::Client::new(mock.url(), Some("secret".into()));
+        let store = Store::in_memory();
+        store
+            .write(|db| {
+                db.dst_alerts.push(DstAlert {
+                    channel: Id::new(42),
+                    zone: "America/New_York".into(),
+                    lead: 3,
+                    notified: None,
+                })
+            })
+            .unwrap();
+
+        // Four days before the 2025-03-09 spring-forward transition: too early.
+        let clock = FakeClock::new("2025-03-05T06:00:00Z".parse().unwrap());
+        super::tick(&store, &client, clock.now()).await;
+        assert!(mock.requests().is_empty());
+
+        // Two days before the transition: within the lead time.
+        clock.set("2025-03-07T06:00:00Z".parse().unwrap());
+        super::tick(&store, &client, clock.now()).await;
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/channels/42/messages");
+        assert_eq!(requests[0].authorization.as_deref(), Some("Bot secret"));
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        let content = body["content"].as_str().unwrap();
+        assert!(content.contains("EST (UTC-05:00) to EDT (UTC-04:00)"), "{content}");
+
+        // The same transition must not be announced again.
+        clock.set("2025-03-08T06:00:00Z".parse().unwrap());
+        super::tick(&store, &client, clock.now()).await;
+        assert_eq!(mock.requests().len(), 1);
+
+        // The autumn transition is announced separately.
+        clock.set("2025-10-31T06:00:00Z".parse().unwrap());
+        super::tick(&store, &client, clock.now()).await;
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_posts_are_retried() {
+        let mock = Mock::start().await;
+        mock.fail_next(hyper::StatusCode::INTERNAL_SERVER_ERROR);
+        let client = rest::Client::new(mock.url(), Some("secret".into()));
+        let store = Store::in_memory();
+        store
+            .write(|db| {
+                db.dst_alerts.push(DstAlert {
+                    channel: Id::new(7),
+                    zone: "Europe/Berlin".into(),
+                    lead: 1,
+                    notified: None,
+                })
+            })
+            .unwrap();
+
+        let now = "2025-03-29T12:00:00Z".parse().unwrap();
+        super::tick(&store, &client, now).await;
+        assert!(store.read(|db| db.dst_alerts[0].notified.is_none()));
+
+        super::tick(&store, &client, now).await;
+        assert_eq!(mock.requests().len(), 2);
+        assert!(store.read(|db| db.dst_alerts[0].notified.is_some()));
+    }
+}
@@ -0,0 +1,85 @@
+pub mod birthday;
+pub mod countdown;
+pub mod dst;
+
+use jiff::Timestamp;
+
+/// Source of the current instant. Background jobs never read the system time directly
+/// so that tests may substitute a [`FakeClock`].
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Timestamp;
+}
+
+/// The real wall clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// Manually adjustable clock for tests.
+#[cfg(test)]
+pub struct FakeClock(std::sync::Mutex<Timestamp>);
+
+#[cfg(test)]
+impl FakeClock {
+    pub fn new(now: Timestamp) -> Self {
+        Self(std::sync::Mutex::new(now))
+    }
+
+    pub fn set(&self, now: Timestamp) {
+        *self.0.lock().unwrap() = now;
+    }
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+    fn now(&self) -> Timestamp {
+        *self.0.lock().unwrap()
+    }
+}
+
+/// Spawns every background job onto the current Tokio runtime.
+pub fn spawn(state: &std::sync::Arc<crate::State>) {
+    let inner = state.clone();
+    tokio::spawn(async move {
+        let state = inner.as_ref();
+        every(state.clock.as_ref(), dst::PERIOD, |now| dst::tick(&state.store, &state.rest, now))
+            .await;
+    });
+
+    let inner = state.clone();
+    tokio::spawn(async move {
+        let state = inner.as_ref();
+        every(state.clock.as_ref(), birthday::PERIOD, |now| {
+            birthday::tick(&state.store, &state.rest, now)
+        })
+        .await;
+    });
+
+    let inner = state.clone();
+    tokio::spawn(async move {
+        let state = inner.as_ref();
+        every(state.clock.as_ref(), countdown::PERIOD, |now| {
+            countdown::tick(&state.store, &state.rest, now)
+        })
+        .await;
+    });
+}
+
+/// Invokes `task` with the current time once every `period`. Ticks which are missed
+/// because a previous invocation ran long are skipped rather than bunched up.
+pub async fn every<F, Fut>(clock: &dyn Clock, period: core::time::Duration, mut task: F)
+where
+    F: FnMut(Timestamp) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        interval.tick().await;
+        task(clock.now()).await;
+    }
+}
@@ -1,11 +1,52 @@
+pub mod job;
+pub mod registry;
+pub mod rest;
+pub mod store;
+pub mod util;
+
+mod i18n;
 mod interaction;
-mod util;
 
 use http_body_util::Full;
 use hyper::{
     HeaderMap, Method, Response, StatusCode,
     body::{Bytes, Incoming},
 };
+use twilight_model::{
+    application::command::Command,
+    id::{
+        Id,
+        marker::{ApplicationMarker, GuildMarker},
+    },
+};
+
+/// Shared state for the request handlers and the background jobs.
+pub struct State {
+    pub pub_key: ed25519_dalek::VerifyingKey,
+    pub store: store::Store,
+    pub rest: rest::Client,
+    pub clock: Box<dyn job::Clock>,
+    /// How long a handler may run before its response is automatically deferred.
+    pub budget: core::time::Duration,
+    /// Registered commands by which interactions are routed. If absent, commands are
+    /// routed by their names alone without verifying their IDs.
+    pub commands: Option<registry::Registry>,
+    /// Abbreviations of every zone in the database within the configured years, which
+    /// back the `/abbr` command.
+    pub abbreviations: util::abbr::Abbreviations,
+    /// Sessions of autocompletion from which the popularity prior is learned.
+    pub learner: util::popularity::Learner,
+}
+
+/// Registers every command either globally or in the `guild` only. Returns the commands
+/// as registered (i.e., with their IDs).
+pub async fn register(
+    client: &rest::Client,
+    application: Id<ApplicationMarker>,
+    guild: Option<Id<GuildMarker>>,
+) -> rest::Result<Vec<Command>> {
+    client.set_commands(application, guild, &interaction::commands()).await
+}
 
 pub fn from_err_status(code: StatusCode) -> Response<Full<Bytes>> {
     let mut res = Response::new(Full::new(Bytes::new()));
@@ -18,7 +59,7 @@ pub async fn try_respond(
     method: Method,
     path: &str,
     headers: &HeaderMap,
-    pub_key: &ed25519_dalek::VerifyingKey,
+    state: &std::sync::Arc<State>,
 ) -> core::result::Result<Response<Full<Bytes>>, StatusCode> {
     match method {
         Method::GET => {
@@ -65,7 +106,10 @@ pub async fn try_respond(
             log::debug!("Fully received payload body.");
 
             // Validate the challenge
-            pub_key.verify_strict(&message, &signature).map_err(|_| StatusCode::UNAUTHORIZED)?;
+            state
+                .pub_key
+                .verify_strict(&message, &signature)
+                .map_err(|_| StatusCode::UNAUTHORIZED)?;
             log::debug!("Ed25519 signature verified.");
 
             // Parse incoming interaction
@@ -73,7 +117,7 @@ pub async fn try_respond(
             let interaction = serde_json::from_slice(json).map_err(|_| StatusCode::BAD_REQUEST)?;
             log::debug!("Interaction JSON body parsed.");
 
-            let reply = interaction::respond(interaction);
+            let reply = interaction::respond(interaction, state).await;
             let body = serde_json::to_string(&reply)
                 .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
                 .into();
@@ -1,6 +1,17 @@
 fn main() -> anyhow::Result<()> {
     use std::{env::var, net};
 
+    // Register the slash commands instead of running the server
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some("register") {
+        let guild = match (args.next().as_deref(), args.next()) {
+            (None, _) => None,
+            (Some("--guild"), Some(guild)) => Some(guild.parse()?),
+            _ => anyhow::bail!("usage: chrozone register [--guild ID]"),
+        };
+        return register(guild);
+    }
+
     // Retrieve network port
     let port: u16 = var("PORT")?.parse()?;
 
@@ -10,34 +21,133 @@ fn main() -> anyhow::Result<()> {
     hex::decode_to_slice(pub_key, &mut pub_bytes)?;
     let pub_key = ed25519_dalek::VerifyingKey::from_bytes(&pub_bytes)?;
 
+    env_logger::init();
+
+    // Open the persistent store (if configured)
+    let store = match var("DATA_PATH") {
+        Ok(path) => chrozone::store::Store::open(path)?,
+        Err(_) => {
+            log::warn!("No DATA_PATH configured. Nothing will be persisted across restarts.");
+            chrozone::store::Store::in_memory()
+        }
+    };
+
+    // Configure the outbound Discord API client
+    let token = var("TOKEN").ok().map(String::into_boxed_str);
+    let has_token = token.is_some();
+    let base = var("DISCORD_API").unwrap_or_else(|_| String::from(chrozone::rest::DISCORD_API));
+
+    // Retrieve the time budget of handlers before their responses are deferred
+    let budget = match var("RESPONSE_BUDGET_MS") {
+        Ok(millis) => core::time::Duration::from_millis(millis.parse()?),
+        Err(_) => core::time::Duration::from_secs(2),
+    };
+
+    // Load the registered commands (if configured) for routing by their IDs
+    let commands = match var("COMMANDS_PATH") {
+        Ok(path) => {
+            let registry = chrozone::registry::Registry::open(path)?;
+            if registry.is_empty() {
+                log::warn!("No registered commands found. Every command will be rejected.");
+            }
+            Some(registry)
+        }
+        Err(_) => {
+            log::warn!("No COMMANDS_PATH configured. Command IDs will not be verified.");
+            None
+        }
+    };
+
+    // Build the timezone indices ahead of the first autocompletion and resolution
+    let clock = chrozone::job::SystemClock;
+    let index = &*chrozone::util::index::TIMEZONES;
+    log::info!("Indexed {} timezones for autocompletion.", index.len());
+    let year = chrozone::job::Clock::now(&clock).to_zoned(jiff::tz::TimeZone::UTC).year();
+    let recent = chrozone::util::abbr::init(year);
+    log::info!(
+        "Indexed {} abbreviations in use within {:?} for timezone resolution.",
+        recent.len(),
+        recent.years()
+    );
+
+    // Scan the abbreviations of every zone within the configured years for `/abbr`
+    let years = match var("ABBREVIATION_YEARS") {
+        Ok(years) => {
+            let (start, end) = years
+                .split_once('-')
+                .ok_or_else(|| anyhow::anyhow!("ABBREVIATION_YEARS must be START-END"))?;
+            let (start, end): (i16, i16) = (start.trim().parse()?, end.trim().parse()?);
+            if start > end {
+                anyhow::bail!("ABBREVIATION_YEARS must not start after its end");
+            }
+            start..=end
+        }
+        Err(_) => 1970..=2037,
+    };
+    let zones: Vec<_> = jiff::tz::db().available().collect();
+    let all =
+        chrozone::util::abbr::Abbreviations::scan(zones.iter().map(|zone| zone.as_str()), years);
+    log::info!(
+        "Scanned {} historical abbreviations within {:?} for /abbr.",
+        all.len(),
+        all.years()
+    );
+
+    // Configure the popularity prior learned from the picked timezones
+    let mut prior = chrozone::util::popularity::Prior::DEFAULT;
+    if let Ok(weight) = var("POPULARITY_WEIGHT") {
+        prior.weight = weight.parse()?;
+    }
+    if let Ok(days) = var("POPULARITY_HALF_LIFE_DAYS") {
+        prior.half_life = jiff::SignedDuration::try_from_secs_f64(days.parse::<f64>()? * 86400.0)?;
+    }
+    let log = var("PICK_LOG_PATH").ok().map(std::path::PathBuf::from);
+    let learner = chrozone::util::popularity::Learner::new(prior, log);
+
     let listener = net::TcpListener::bind((net::Ipv4Addr::UNSPECIFIED, port))?;
     listener.set_nonblocking(true)?;
 
-    let runtime = tokio::runtime::Builder::new_multi_thread().enable_io().build()?;
-    let tcp = {
+    let runtime = tokio::runtime::Builder::new_multi_thread().enable_io().enable_time().build()?;
+    let (tcp, rest) = {
         let _guard = runtime.enter();
-        tokio::net::TcpListener::from_std(listener)?
+        (tokio::net::TcpListener::from_std(listener)?, chrozone::rest::Client::new(base, token))
     };
 
+    let state = std::sync::Arc::new(chrozone::State {
+        pub_key,
+        store,
+        rest,
+        clock: Box::new(clock),
+        budget,
+        commands,
+        abbreviations: all,
+        learner,
+    });
+
+    // Spawn the background jobs, which all require a bot token
+    if has_token {
+        let _guard = runtime.enter();
+        chrozone::job::spawn(&state);
+    } else {
+        log::warn!("No TOKEN configured. Background jobs are disabled.");
+    }
+
     // Listen for new connections
-    let arc_pub_key = std::sync::Arc::new(pub_key);
     let http = hyper::server::conn::http1::Builder::new();
-
-    env_logger::init();
     runtime.block_on(async {
         loop {
             let Ok((stream, _)) = tcp.accept().await else {
                 continue;
             };
 
-            let outer = arc_pub_key.clone();
+            let outer = state.clone();
             let service = hyper::service::service_fn(move |req| {
                 let inner = outer.clone();
                 let (hyper::http::request::Parts { headers, method, uri, .. }, body) =
                     req.into_parts();
                 async move {
                     let response =
-                        chrozone::try_respond(body, method, uri.path(), &headers, inner.as_ref())
+                        chrozone::try_respond(body, method, uri.path(), &headers, &inner)
                             .await
                             .unwrap_or_else(chrozone::from_err_status);
                     Ok::<_, core::convert::Infallible>(response)
@@ -52,3 +162,34 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Overwrites the registered slash commands, either globally or in the `guild` only.
+fn register(
+    guild: Option<twilight_model::id::Id<twilight_model::id::marker::GuildMarker>>,
+) -> anyhow::Result<()> {
+    use std::env::var;
+
+    let application = var("APP_ID")?.parse()?;
+    let token = var("TOKEN")?.into_boxed_str();
+    let base = var("DISCORD_API").unwrap_or_else(|_| String::from(chrozone::rest::DISCORD_API));
+
+    let runtime =
+        tokio::runtime::Builder::new_current_thread().enable_io().enable_time().build()?;
+    let commands = runtime.block_on(async {
+        let client = chrozone::rest::Client::new(base, Some(token));
+        chrozone::register(&client, application, guild).await
+    });
+    let commands = commands.map_err(|err| anyhow::anyhow!("failed to register commands: {err}"))?;
+
+    for command in &commands {
+        let id = command.id.map(|id| id.to_string()).unwrap_or_default();
+        println!("/{} {id}", command.name);
+    }
+
+    // Save the command IDs so that the server may route by them
+    if let Ok(path) = var("COMMANDS_PATH") {
+        chrozone::registry::Registry::save(path, guild, &commands)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+//! Commands as registered with Discord (i.e., along with their IDs). The registration
+//! step saves them to a JSON file which the server then loads so that interactions are
+//! routed by the ID of the invoked command rather than by its name alone.
+
+use std::{collections::HashMap, io, path::Path};
+use twilight_model::{
+    application::command::Command,
+    id::{
+        Id,
+        marker::{CommandMarker, GuildMarker},
+    },
+};
+
+/// Registered commands keyed by their IDs. Global and guild-scoped registrations of the
+/// same name may coexist, which allows canarying new versions of a command on a test guild.
+#[derive(Default)]
+pub struct Registry {
+    commands: HashMap<Id<CommandMarker>, Command>,
+}
+
+impl Registry {
+    /// Indexes the `commands` by their IDs. Commands without IDs are ignored.
+    pub fn new(commands: impl IntoIterator<Item = Command>) -> Self {
+        let commands = commands.into_iter().filter_map(|command| Some((command.id?, command)));
+        Self { commands: commands.collect() }
+    }
+
+    /// Loads the registered commands at `path`. A missing file is treated as empty.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        load(path.as_ref()).map(Self::new)
+    }
+
+    /// Replaces the saved registrations within the scope of the `guild` (or the global
+    /// scope) with the newly registered `commands`. Other scopes are kept as is.
+    pub fn save(
+        path: impl AsRef<Path>,
+        guild: Option<Id<GuildMarker>>,
+        commands: &[Command],
+    ) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut saved = load(path)?;
+        saved.retain(|command| command.guild_id != guild);
+        saved.extend_from_slice(commands);
+
+        // Write to a sibling file first so that a crash never leaves a truncated file.
+        let json = serde_json::to_vec_pretty(&saved).map_err(io::Error::other)?;
+        let temp = path.with_extension("tmp");
+        std::fs::write(&temp, json)?;
+        std::fs::rename(temp, path)
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Resolves the registered command with the given `id` as invoked in the `guild`.
+    /// Stale IDs (e.g., from since overwritten registrations) and guild-scoped commands
+    /// invoked elsewhere are rejected.
+    pub fn resolve(
+        &self,
+        id: Id<CommandMarker>,
+        name: &str,
+        guild: Option<Id<GuildMarker>>,
+    ) -> Option<&Command> {
+        let Some(command) = self.commands.get(&id) else {
+            log::error!("Invoked unregistered command {id} (/{name}) in guild {guild:?}.");
+            return None;
+        };
+
+        if command.name != name {
+            log::error!(
+                "Invoked command {id} as /{name} but it is registered as /{}.",
+                command.name
+            );
+            return None;
+        }
+
+        if command.guild_id.is_some_and(|scope| Some(scope) != guild) {
+            log::error!("Invoked command {id} (/{name}) outside of its guild in {guild:?}.");
+            return None;
+        }
+
+        Some(command)
+    }
+}
+
+fn load(path: &Path) -> io::Result<Vec<Command>> {
+    match std::fs::read(path) {
+        Ok(bytes) => serde_json::from_slice(&bytes).map_err(io::Error::other),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Registry;
+    use twilight_model::{
+        application::command::{Command, CommandType},
+        id::Id,
+    };
+
+    fn command(id: u64, name: &str, guild: Option<u64>) -> Command {
+        #[expect(deprecated, reason = "required by the struct literal")]
+        Command {
+            application_id: Some(Id::new(1)),
+            contexts: None,
+            default_member_permissions: None,
+            dm_permission: None,
+            description: String::from("Description."),
+            description_localizations: None,
+            guild_id: guild.map(Id::new),
+            id: Some(Id::new(id)),
+            integration_types: None,
+            kind: CommandType::ChatInput,
+            name: String::from(name),
+            name_localizations: None,
+            nsfw: None,
+            options: Vec::new(),
+            version: Id::new(1),
+        }
+    }
+
+    #[test]
+    fn resolve_by_id_and_scope() {
+        let registry = Registry::new([command(10, "epoch", None), command(20, "epoch", Some(5))]);
+        assert_eq!(registry.resolve(Id::new(10), "epoch", None).unwrap().id, Some(Id::new(10)));
+        assert_eq!(
+            registry.resolve(Id::new(20), "epoch", Some(Id::new(5))).unwrap().id,
+            Some(Id::new(20))
+        );
+        assert!(registry.resolve(Id::new(20), "epoch", Some(Id::new(6))).is_none());
+        assert!(registry.resolve(Id::new(20), "epoch", None).is_none());
+        assert!(registry.resolve(Id::new(10), "help", None).is_none());
+        assert!(registry.resolve(Id::new(30), "epoch", None).is_none());
+    }
+
+    #[test]
+    fn save_replaces_scope() {
+        let path =
+            std::env::temp_dir().join(format!("chrozone-commands-{}.json", std::process::id()));
+        Registry::save(&path, None, &[command(10, "epoch", None)]).unwrap();
+        Registry::save(&path, Some(Id::new(5)), &[command(20, "epoch", Some(5))]).unwrap();
+        Registry::save(&path, None, &[command(11, "epoch", None)]).unwrap();
+
+        let registry = Registry::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(registry.len(), 2);
+        assert!(registry.resolve(Id::new(10), "epoch", None).is_none());
+        assert!(registry.resolve(Id::new(11), "epoch", None).is_some());
+        assert!(registry.resolve(Id::new(20), "epoch", Some(Id::new(5))).is_some());
+    }
+}
@@ -0,0 +1,104 @@
+//! Local HTTP stand-in for the Discord REST API. Every request is recorded and answered
+//! with an empty JSON object unless a failure has been queued via [`Mock::fail_next`].
+
+use hyper::{StatusCode, body::Bytes};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+pub struct Recorded {
+    pub method: String,
+    pub path: String,
+    pub authorization: Option<String>,
+    pub body: Bytes,
+}
+
+#[derive(Default)]
+struct Shared {
+    requests: Vec<Recorded>,
+    failures: Vec<StatusCode>,
+    reply: Option<Bytes>,
+}
+
+pub struct Mock {
+    addr: std::net::SocketAddr,
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl Mock {
+    pub async fn start() -> Self {
+        let listener = tokio::net::TcpListener::bind((std::net::Ipv4Addr::LOCALHOST, 0))
+            .await
+            .expect("mock server must bind");
+        let addr = listener.local_addr().unwrap();
+        let shared = Arc::<Mutex<Shared>>::default();
+
+        let outer = shared.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    continue;
+                };
+                let shared = outer.clone();
+                let service = hyper::service::service_fn(move |req| {
+                    let shared = shared.clone();
+                    async move { Ok::<_, core::convert::Infallible>(record(&shared, req).await) }
+                });
+                let io = hyper_util::rt::TokioIo::new(stream);
+                tokio::spawn(
+                    hyper::server::conn::http1::Builder::new().serve_connection(io, service),
+                );
+            }
+        });
+
+        Self { addr, shared }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Responds to the next request with the given error status.
+    pub fn fail_next(&self, status: StatusCode) {
+        self.shared.lock().unwrap().failures.push(status);
+    }
+
+    /// Responds to all subsequent successful requests with the given JSON body.
+    pub fn reply_with(&self, body: &str) {
+        self.shared.lock().unwrap().reply = Some(Bytes::copy_from_slice(body.as_bytes()));
+    }
+
+    /// Returns every request received so far.
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.shared.lock().unwrap().requests.clone()
+    }
+}
+
+async fn record(
+    shared: &Mutex<Shared>,
+    req: hyper::Request<hyper::body::Incoming>,
+) -> hyper::Response<http_body_util::Full<Bytes>> {
+    use http_body_util::BodyExt;
+    let (parts, body) = req.into_parts();
+    let body = body.collect().await.map(|body| body.to_bytes()).unwrap_or_default();
+
+    let mut shared = shared.lock().unwrap();
+    shared.requests.push(Recorded {
+        method: parts.method.to_string(),
+        path: parts.uri.path().into(),
+        authorization: parts
+            .headers
+            .get(hyper::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .map(Into::into),
+        body,
+    });
+
+    let (status, body) = if shared.failures.is_empty() {
+        (StatusCode::OK, shared.reply.clone().unwrap_or_else(|| Bytes::from_static(b"{}")))
+    } else {
+        (shared.failures.remove(0), Bytes::from_static(b"{}"))
+    };
+    let mut res = hyper::Response::new(http_body_util::Full::new(body));
+    *res.status_mut() = status;
+    res
+}
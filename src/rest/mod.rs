@@ -0,0 +1,177 @@
+#[cfg(test)]
+pub mod mock;
+
+use core::fmt;
+use http_body_util::Full;
+use hyper::{Method, Request, StatusCode, body::Bytes};
+use hyper_rustls::HttpsConnector;
+use hyper_util::client::legacy::{Client as HttpClient, connect::HttpConnector};
+use twilight_model::{
+    application::command::Command,
+    http::interaction::InteractionResponseData,
+    id::{
+        Id,
+        marker::{ApplicationMarker, ChannelMarker, GuildMarker, MessageMarker},
+    },
+};
+
+/// Production base URL of the Discord REST API.
+pub const DISCORD_API: &str = "https://discord.com/api/v10";
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+pub enum Error {
+    Body(hyper::Error),
+    Deserialize,
+    InvalidUri,
+    MissingToken,
+    Request(hyper_util::client::legacy::Error),
+    Serialize,
+    Status(StatusCode),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Body(err) => write!(f, "failed to read response body: {err}"),
+            Self::Deserialize => f.write_str("failed to deserialize response body"),
+            Self::InvalidUri => f.write_str("invalid request URI"),
+            Self::MissingToken => f.write_str("no bot token configured"),
+            Self::Request(err) => write!(f, "request failed: {err}"),
+            Self::Serialize => f.write_str("failed to serialize request body"),
+            Self::Status(code) => write!(f, "unexpected response status {code}"),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct MessageContent<'a> {
+    content: &'a str,
+}
+
+/// Location of a message as returned by the API.
+#[derive(serde::Deserialize)]
+pub struct MessageRef {
+    pub id: Id<MessageMarker>,
+    pub channel_id: Id<ChannelMarker>,
+}
+
+/// Minimal outbound client for the Discord REST API. The base URL is configurable
+/// so that tests (and staging deployments) may point it at a local stand-in.
+pub struct Client {
+    http: HttpClient<HttpsConnector<HttpConnector>, Full<Bytes>>,
+    base: Box<str>,
+    token: Option<Box<str>>,
+}
+
+impl Client {
+    pub fn new(base: impl Into<Box<str>>, token: Option<Box<str>>) -> Self {
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http1()
+            .build();
+        let http = HttpClient::builder(hyper_util::rt::TokioExecutor::new()).build(connector);
+        let mut base = base.into().into_string();
+        base.truncate(base.trim_end_matches('/').len());
+        Self { http, base: base.into_boxed_str(), token }
+    }
+
+    /// Posts a plain-text message to a channel on behalf of the bot user.
+    pub async fn create_message(&self, channel: Id<ChannelMarker>, content: &str) -> Result<()> {
+        let body = serde_json::to_vec(&MessageContent { content }).map_err(|_| Error::Serialize)?;
+        self.request(Method::POST, &format!("/channels/{channel}/messages"), body, true).await?;
+        Ok(())
+    }
+
+    /// Edits the original response of an interaction. This requires no bot token, but the
+    /// interaction token expires 15 minutes after the interaction was received.
+    pub async fn update_original(
+        &self,
+        application: Id<ApplicationMarker>,
+        token: &str,
+        content: &str,
+    ) -> Result<MessageRef> {
+        let body = serde_json::to_vec(&MessageContent { content }).map_err(|_| Error::Serialize)?;
+        let path = format!("/webhooks/{application}/{token}/messages/@original");
+        let bytes = self.request(Method::PATCH, &path, body, false).await?;
+        serde_json::from_slice(&bytes).map_err(|_| Error::Deserialize)
+    }
+
+    /// Replaces the original response of an interaction with the full message `data`
+    /// (e.g., embeds and components). This is how deferred responses are completed.
+    pub async fn update_original_with(
+        &self,
+        application: Id<ApplicationMarker>,
+        token: &str,
+        data: &InteractionResponseData,
+    ) -> Result<()> {
+        let body = serde_json::to_vec(data).map_err(|_| Error::Serialize)?;
+        let path = format!("/webhooks/{application}/{token}/messages/@original");
+        self.request(Method::PATCH, &path, body, false).await?;
+        Ok(())
+    }
+
+    /// Edits a message previously posted by the bot user.
+    pub async fn update_message(
+        &self,
+        channel: Id<ChannelMarker>,
+        message: Id<MessageMarker>,
+        content: &str,
+    ) -> Result<()> {
+        let body = serde_json::to_vec(&MessageContent { content }).map_err(|_| Error::Serialize)?;
+        let path = format!("/channels/{channel}/messages/{message}");
+        self.request(Method::PATCH, &path, body, true).await?;
+        Ok(())
+    }
+
+    /// Overwrites every command of the application, either globally or in the `guild` only.
+    /// Returns the commands as registered (i.e., with their IDs).
+    pub async fn set_commands(
+        &self,
+        application: Id<ApplicationMarker>,
+        guild: Option<Id<GuildMarker>>,
+        commands: &[Command],
+    ) -> Result<Vec<Command>> {
+        let body = serde_json::to_vec(commands).map_err(|_| Error::Serialize)?;
+        let path = match guild {
+            Some(guild) => format!("/applications/{application}/guilds/{guild}/commands"),
+            None => format!("/applications/{application}/commands"),
+        };
+        let bytes = self.request(Method::PUT, &path, body, true).await?;
+        serde_json::from_slice(&bytes).map_err(|_| Error::Deserialize)
+    }
+
+    /// Sends a JSON request to `path` (relative to the base URL) and returns the raw response
+    /// body. The bot token is only attached when `auth` is set.
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Vec<u8>,
+        auth: bool,
+    ) -> Result<Bytes> {
+        use http_body_util::BodyExt;
+        use hyper::header::{AUTHORIZATION, CONTENT_TYPE};
+
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(format!("{}{path}", self.base))
+            .header(CONTENT_TYPE, "application/json");
+        if auth {
+            let token = self.token.as_deref().ok_or(Error::MissingToken)?;
+            builder = builder.header(AUTHORIZATION, format!("Bot {token}"));
+        }
+
+        let req = builder.body(Full::new(Bytes::from(body))).map_err(|_| Error::InvalidUri)?;
+        let res = self.http.request(req).await.map_err(Error::Request)?;
+        let status = res.status();
+        let bytes = res.into_body().collect().await.map_err(Error::Body)?.to_bytes();
+        if !status.is_success() {
+            log::error!("Discord API responded with {status}: {}", String::from_utf8_lossy(&bytes));
+            return Err(Error::Status(status));
+        }
+
+        Ok(bytes)
+    }
+}
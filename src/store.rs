@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use std::{io, path::PathBuf, sync::Mutex};
+use twilight_model::id::{
+    Id,
+    marker::{ApplicationMarker, ChannelMarker, MessageMarker, UserMarker},
+};
+
+/// A daylight saving time alert subscription for some channel.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct DstAlert {
+    pub channel: Id<ChannelMarker>,
+    pub zone: Box<str>,
+    /// Number of days before the transition at which the alert is posted.
+    pub lead: u16,
+    /// Timestamp of the most recent transition that has already been announced.
+    pub notified: Option<jiff::Timestamp>,
+}
+
+/// Which day to celebrate February 29 birthdays on in common years.
+#[derive(Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum LeapFallback {
+    #[default]
+    Feb28,
+    Mar1,
+}
+
+/// A birthday announcement registered by some user in some channel.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Birthday {
+    pub user: Id<UserMarker>,
+    pub channel: Id<ChannelMarker>,
+    pub month: i8,
+    pub day: i8,
+    pub zone: Box<str>,
+    #[serde(default)]
+    pub fallback: LeapFallback,
+    /// The most recent (local) year in which the birthday was announced.
+    pub announced: Option<i16>,
+}
+
+impl Birthday {
+    /// Returns the date on which the birthday is celebrated in the given `year`.
+    pub fn occurrence(&self, year: i16) -> jiff::civil::Date {
+        use jiff::civil::date;
+        let is_leap = date(year, 1, 1).in_leap_year();
+        match (self.month, self.day, is_leap, self.fallback) {
+            (2, 29, false, LeapFallback::Feb28) => date(year, 2, 28),
+            (2, 29, false, LeapFallback::Mar1) => date(year, 3, 1),
+            (month, day, ..) => date(year, month, day),
+        }
+    }
+}
+
+/// A public countdown message which is periodically edited until its target.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Countdown {
+    pub application: Id<ApplicationMarker>,
+    /// Interaction token for editing the original response. Only valid for 15 minutes.
+    pub token: Box<str>,
+    /// When the interaction (and thus the token) was created.
+    pub created: jiff::Timestamp,
+    pub target: jiff::Timestamp,
+    pub title: Option<Box<str>>,
+    /// Location of the message, which is learned from the first webhook edit. This is
+    /// required for editing the message via the bot token once the interaction expires.
+    pub channel: Option<Id<ChannelMarker>>,
+    pub message: Option<Id<MessageMarker>>,
+}
+
+/// Timezones which some user has chosen before, which are suggested first.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Preferences {
+    pub user: Id<UserMarker>,
+    /// Zones pinned by the user in the order pinned.
+    #[serde(default)]
+    pub favorites: Vec<Box<str>>,
+    /// Zones most recently submitted by the user (most recent first).
+    #[serde(default)]
+    pub recent: Vec<Box<str>>,
+    /// Whether the user has opted out of recording their recent zones.
+    #[serde(default)]
+    pub no_history: bool,
+}
+
+/// Everything that Chrozone persists across restarts.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Database {
+    pub dst_alerts: Vec<DstAlert>,
+    pub birthdays: Vec<Birthday>,
+    pub countdowns: Vec<Countdown>,
+    pub preferences: Vec<Preferences>,
+    /// Timezones picked after autocompleting, which rank popular zones higher.
+    pub popularity: crate::util::popularity::Popularity,
+}
+
+impl Database {
+    /// Returns the preferences of the `user`, which are created on first use.
+    pub fn preferences_mut(&mut self, user: Id<UserMarker>) -> &mut Preferences {
+        let index = match self.preferences.iter().position(|preferences| preferences.user == user) {
+            Some(index) => index,
+            None => {
+                self.preferences.push(Preferences {
+                    user,
+                    favorites: Vec::new(),
+                    recent: Vec::new(),
+                    no_history: false,
+                });
+                self.preferences.len() - 1
+            }
+        };
+        &mut self.preferences[index]
+    }
+}
+
+/// Thread-safe wrapper over the [`Database`] which is (optionally) backed by a JSON file.
+/// Every successful [`Store::write`] flushes the whole database to disk.
+pub struct Store {
+    path: Option<PathBuf>,
+    db: Mutex<Database>,
+}
+
+impl Store {
+    /// Creates a store that never touches the file system.
+    pub fn in_memory() -> Self {
+        Self { path: None, db: Mutex::default() }
+    }
+
+    /// Loads the database at `path`. A missing file is treated as an empty database.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let db = match std::fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(io::Error::other)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Database::default(),
+            Err(err) => return Err(err),
+        };
+        Ok(Self { path: Some(path), db: Mutex::new(db) })
+    }
+
+    pub fn read<R>(&self, f: impl FnOnce(&Database) -> R) -> R {
+        let db = self.db.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        f(&db)
+    }
+
+    /// Mutates the database and then persists the result. Note that the in-memory
+    /// mutation is kept even if the flush to disk fails.
+    pub fn write<R>(&self, f: impl FnOnce(&mut Database) -> R) -> io::Result<R> {
+        let mut db = self.db.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let result = f(&mut db);
+        let Some(path) = self.path.as_ref() else {
+            return Ok(result);
+        };
+
+        // Write to a sibling file first so that a crash never leaves a truncated database.
+        let json = serde_json::to_vec(&*db).map_err(io::Error::other)?;
+        let temp = path.with_extension("tmp");
+        std::fs::write(&temp, json)?;
+        std::fs::rename(temp, path)?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,213 @@
+//! Index of the abbreviations (e.g., `PST`, `CEST`, `IST`) used by the zones of the IANA
+//! Time Zone Database, which is precomputed by scanning their transitions within a range
+//! of years. Lookups work both ways: from an abbreviation to the zones which used it and
+//! from a zone to every abbreviation it used.
+
+use core::ops::RangeInclusive;
+use hashbrown::HashMap;
+use jiff::{
+    Timestamp,
+    tz::{Offset, TimeZone},
+};
+use std::sync::OnceLock;
+
+/// Abbreviations of the canonical zones in use around the current year, which timezone
+/// resolution consults (see [`super::tz::resolve`]). No abbreviation resolves before they
+/// are built by [`init`].
+pub static RECENT: OnceLock<Abbreviations> = OnceLock::new();
+
+/// Builds the [`RECENT`] abbreviations from the year before the current `year` until the
+/// year after (unless already built).
+pub fn init(year: i16) -> &'static Abbreviations {
+    RECENT.get_or_init(|| Abbreviations::scan(super::index::zones(), year - 1..=year + 1))
+}
+
+/// A span of time (exclusive of its end) during which a zone used an abbreviation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Period {
+    pub start: Timestamp,
+    pub end: Timestamp,
+}
+
+/// A zone which used an abbreviation for some offset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Usage {
+    pub zone: Box<str>,
+    pub abbreviation: Box<str>,
+    pub offset: Offset,
+    /// Periods of use in chronological order, which are clipped to the scanned years.
+    pub periods: Vec<Period>,
+}
+
+pub struct Abbreviations {
+    /// Usages grouped by zone (in the order scanned), each in the order of first use.
+    usages: Vec<Usage>,
+    /// Indices of the usages keyed by the uppercase abbreviation.
+    by_abbreviation: HashMap<Box<str>, Vec<usize>>,
+    /// Indices of the usages keyed by the zone.
+    by_zone: HashMap<Box<str>, core::ops::Range<usize>>,
+    years: RangeInclusive<i16>,
+}
+
+impl Default for Abbreviations {
+    fn default() -> Self {
+        Self {
+            usages: Vec::new(),
+            by_abbreviation: HashMap::new(),
+            by_zone: HashMap::new(),
+            // No years have been scanned.
+            years: RangeInclusive::new(1, 0),
+        }
+    }
+}
+
+impl Abbreviations {
+    /// Scans every transition of the `zones` from the start of the first of the `years`
+    /// until the end of the last (in UTC).
+    pub fn scan<'z>(zones: impl IntoIterator<Item = &'z str>, years: RangeInclusive<i16>) -> Self {
+        let start = jiff::civil::date(*years.start(), 1, 1).to_zoned(TimeZone::UTC);
+        let end = years
+            .end()
+            .checked_add(1)
+            .and_then(|year| jiff::civil::Date::new(year, 1, 1).ok()?.to_zoned(TimeZone::UTC).ok());
+        let (Ok(start), Some(end)) = (start, end) else {
+            log::error!("Cannot scan abbreviations within the years {years:?}.");
+            return Self::default();
+        };
+        let (start, end) = (start.timestamp(), end.timestamp());
+
+        let mut index = Self { years, ..Self::default() };
+        for zone in zones {
+            let Ok(tz) = TimeZone::get(zone) else {
+                log::error!("Cannot scan abbreviations of unknown zone {zone}.");
+                continue;
+            };
+
+            let first = index.usages.len();
+            let info = tz.to_offset_info(start);
+            let mut current = (Box::<str>::from(info.abbreviation()), info.offset(), start);
+            for transition in
+                tz.following(start).take_while(|transition| transition.timestamp() < end)
+            {
+                let (abbreviation, offset, from) = current;
+                index.record(
+                    first,
+                    zone,
+                    abbreviation,
+                    offset,
+                    Period { start: from, end: transition.timestamp() },
+                );
+                current =
+                    (transition.abbreviation().into(), transition.offset(), transition.timestamp());
+            }
+            let (abbreviation, offset, from) = current;
+            index.record(first, zone, abbreviation, offset, Period { start: from, end });
+            index.by_zone.insert(zone.into(), first..index.usages.len());
+        }
+
+        for (id, usage) in index.usages.iter().enumerate() {
+            let key = usage.abbreviation.to_ascii_uppercase().into_boxed_str();
+            index.by_abbreviation.entry(key).or_default().push(id);
+        }
+        index
+    }
+
+    /// Records the `period` into the usages of the current zone, which start at `first`.
+    fn record(
+        &mut self,
+        first: usize,
+        zone: &str,
+        abbreviation: Box<str>,
+        offset: Offset,
+        period: Period,
+    ) {
+        // Numeric abbreviations (e.g., `+08`) merely restate the offset.
+        if abbreviation.starts_with(['+', '-']) {
+            return;
+        }
+        let usages = &mut self.usages[first..];
+        match usages
+            .iter_mut()
+            .find(|usage| usage.abbreviation == abbreviation && usage.offset == offset)
+        {
+            // Transitions which change neither (e.g., only the rules) extend the last period.
+            Some(usage) => match usage.periods.last_mut() {
+                Some(last) if last.end == period.start => last.end = period.end,
+                _ => usage.periods.push(period),
+            },
+            None => self.usages.push(Usage {
+                zone: zone.into(),
+                abbreviation,
+                offset,
+                periods: Vec::from([period]),
+            }),
+        }
+    }
+
+    /// The years which have been scanned.
+    pub fn years(&self) -> RangeInclusive<i16> {
+        self.years.clone()
+    }
+
+    /// Number of distinct abbreviations.
+    pub fn len(&self) -> usize {
+        self.by_abbreviation.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_abbreviation.is_empty()
+    }
+
+    /// Iterates over every distinct (uppercase) abbreviation in an unspecified order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.by_abbreviation.keys().map(|name| &**name)
+    }
+
+    /// Looks up the usages of the (case-insensitive) `abbreviation` in the order scanned.
+    pub fn get(&self, abbreviation: &str) -> Vec<&Usage> {
+        let ids = self.by_abbreviation.get(abbreviation.to_ascii_uppercase().as_str());
+        ids.into_iter().flatten().map(|&id| &self.usages[id]).collect()
+    }
+
+    /// Looks up every abbreviation used by the `zone` in the order of first use.
+    pub fn of(&self, zone: &str) -> &[Usage] {
+        self.by_zone.get(zone).map_or(&[], |ids| &self.usages[ids.clone()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Abbreviations;
+
+    #[test]
+    fn scan_both_ways() {
+        let index =
+            Abbreviations::scan(["Asia/Kolkata", "Europe/Dublin", "America/New_York"], 2020..=2021);
+        assert_eq!(index.years(), 2020..=2021);
+
+        let zones: Vec<_> = index.get("ist").iter().map(|usage| &*usage.zone).collect();
+        assert_eq!(zones, ["Asia/Kolkata", "Europe/Dublin"]);
+
+        // Dublin uses IST in the summer and GMT in the winter.
+        let dublin = index.of("Europe/Dublin");
+        let abbreviations: Vec<_> = dublin.iter().map(|usage| &*usage.abbreviation).collect();
+        assert_eq!(abbreviations, ["GMT", "IST"]);
+        assert_eq!(dublin[0].periods.len(), 3);
+        assert_eq!(dublin[1].periods.len(), 2);
+
+        // Kolkata has not changed at all in the meantime.
+        let kolkata = index.of("Asia/Kolkata");
+        assert_eq!(kolkata.len(), 1);
+        assert_eq!(kolkata[0].periods.len(), 1);
+        assert_eq!(kolkata[0].periods[0].start.to_string(), "2020-01-01T00:00:00Z");
+        assert_eq!(kolkata[0].periods[0].end.to_string(), "2022-01-01T00:00:00Z");
+
+        assert!(index.get("XYZ").is_empty());
+        assert!(index.of("Mars/Olympus").is_empty());
+
+        // Numeric abbreviations are not recorded at all.
+        let index = Abbreviations::scan(["America/Sao_Paulo", "Asia/Manila"], 2020..=2021);
+        assert!(index.of("America/Sao_Paulo").is_empty());
+        assert!(index.get("-03").is_empty());
+    }
+}
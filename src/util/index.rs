@@ -0,0 +1,307 @@
+//! Search index over the names in the IANA Time Zone Database along with their aliases
+//! (i.e., countries, country codes, and major cities). Candidates are narrowed down via
+//! trigram postings and word prefixes before the (comparatively expensive) fuzzy scoring
+//! so that each keystroke does not scan the entire database.
+
+use super::{
+    float::TotalDouble,
+    popularity::Boosts,
+    score::{self, Scorer, Segmented},
+    sort,
+};
+use core::cmp::Reverse;
+use hashbrown::HashMap;
+use std::sync::LazyLock;
+
+/// Aliases of the timezones as generated by `cargo run --example zonedata`.
+const ALIASES: &str = include_str!("zones.tsv");
+
+/// The index over every timezone in the system database, which is built on first use.
+pub static TIMEZONES: LazyLock<Index> = LazyLock::new(|| {
+    let names = jiff::tz::db().available().map(|tz| tz.as_str().into());
+    Index::new(names, aliases(ALIASES))
+});
+
+/// Case-folded trigram.
+type Trigram = [u8; 3];
+
+/// A timezone which matched a query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match<'a> {
+    pub zone: &'a str,
+    /// The alias by which the zone matched (if not by its own name).
+    pub alias: Option<&'a str>,
+}
+
+/// Searchable text which leads to a timezone.
+struct Key {
+    /// ID of the zone in [`Index::names`].
+    zone: u32,
+    /// The alias itself, which is absent for the name of the zone.
+    alias: Option<Box<str>>,
+    /// Case-folded form of the alias or the name (see [`score::fold`]).
+    folded: Box<str>,
+}
+
+pub struct Index {
+    /// Names in the order given (i.e., that of the database), which breaks ties between
+    /// equally similar zones the same way as the full scan that preceded the index.
+    names: Box<[Box<str>]>,
+    /// IDs of the names sorted alphabetically for lookups via binary search.
+    sorted: Box<[u32]>,
+    /// Names of the zones (in the order of their IDs) followed by their aliases (in the
+    /// order given). Earlier keys win ties.
+    keys: Box<[Key]>,
+    /// IDs of the keys which contain each (case-folded) trigram in ascending order.
+    postings: HashMap<Trigram, Box<[u32]>>,
+    /// Every case-folded word (see [`score::words`]) of every key along with the ID of
+    /// its key, sorted for prefix lookups via binary search.
+    segments: Box<[(Box<str>, u32)]>,
+}
+
+impl Index {
+    /// Indexes the `names` of the zones along with their `aliases` given as pairs of an
+    /// alias and the name of its zone. Aliases of unknown zones are ignored.
+    pub fn new<'a>(
+        names: impl IntoIterator<Item = Box<str>>,
+        aliases: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let mut seen = hashbrown::HashSet::new();
+        let names: Vec<_> = names.into_iter().filter(|name| seen.insert(name.clone())).collect();
+        let mut sorted: Vec<_> = (0..names.len() as u32).collect();
+        sorted.sort_unstable_by_key(|&id| &names[id as usize]);
+        let lookup = |name: &str| {
+            let found = sorted.binary_search_by(|&id| (*names[id as usize]).cmp(name)).ok()?;
+            Some(sorted[found])
+        };
+
+        let mut keys: Vec<_> = (0..)
+            .zip(&names)
+            .map(|(zone, name)| Key { zone, alias: None, folded: score::fold(name).into() })
+            .collect();
+        keys.extend(aliases.into_iter().filter_map(|(alias, name)| {
+            Some(Key {
+                zone: lookup(name)?,
+                alias: Some(alias.into()),
+                folded: score::fold(alias).into(),
+            })
+        }));
+
+        let mut postings = HashMap::<_, Vec<_>>::new();
+        let mut segments = Vec::new();
+        for (id, key) in (0..).zip(&keys) {
+            for trigram in trigrams(&key.folded) {
+                let ids = postings.entry(trigram).or_default();
+                // Keys are visited in order, so only the last ID may be a duplicate.
+                if ids.last() != Some(&id) {
+                    ids.push(id);
+                }
+            }
+            segments.extend(score::words(&key.folded).map(|segment| (segment.into(), id)));
+        }
+        segments.sort_unstable();
+
+        Self {
+            names: names.into_boxed_slice(),
+            sorted: sorted.into_boxed_slice(),
+            keys: keys.into_boxed_slice(),
+            postings: postings.into_iter().map(|(trigram, ids)| (trigram, ids.into())).collect(),
+            segments: segments.into_boxed_slice(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Looks up the exact `name` of an indexed zone.
+    pub fn name(&self, name: &str) -> Option<&str> {
+        let id = self.sorted.binary_search_by(|&id| (*self.names[id as usize]).cmp(name)).ok()?;
+        Some(&self.names[self.sorted[id] as usize])
+    }
+
+    /// Returns (up to) the top `count` zones which are the most similar to the `query`
+    /// in descending order of similarity. Each zone appears once by its best key.
+    pub fn search(&self, query: &str, count: usize) -> Vec<Match<'_>> {
+        self.search_with(query, count, &Segmented::DEFAULT, None)
+    }
+
+    /// Like [`Index::search`], but the keys are scored by the `scorer` and the scores of
+    /// the zones are raised by their `boosts` (e.g., from their popularity).
+    pub fn search_with(
+        &self,
+        query: &str,
+        count: usize,
+        scorer: &dyn Scorer,
+        boosts: Option<&Boosts>,
+    ) -> Vec<Match<'_>> {
+        let query = score::fold(query);
+        let mut candidates = self.candidates(&query);
+        if candidates.len() <= count {
+            // Too few candidates to fill the results, so fall back to a full scan.
+            candidates = (0..self.keys.len() as u32).collect();
+        }
+
+        // Each zone is scored by its best key, where earlier keys win ties.
+        let mut best = vec![None::<(f64, u32)>; self.names.len()];
+        for id in candidates {
+            let key = &self.keys[id as usize];
+            let score = scorer.score(&key.folded, &query);
+            let slot = &mut best[key.zone as usize];
+            if slot.is_none_or(|(other, _)| other < score) {
+                *slot = Some((score, id));
+            }
+        }
+
+        // The zones are then selected in the order of their IDs like the full scan, which
+        // leaves ties between names to the partial sort. Names win ties over aliases, which
+        // keep their order given.
+        let mut scored: Vec<_> = (0..)
+            .zip(best)
+            .filter_map(|(zone, best)| {
+                let (score, id) = best?;
+                let boost = boosts.map_or(0.0, |boosts| boosts.get(&self.names[zone]));
+                Some((score + boost, id))
+            })
+            .collect();
+        let aliases = self.names.len() as u32;
+        sort::top_n_by_key(&mut scored, count, |&(score, id)| {
+            (Reverse(TotalDouble(score)), id.saturating_sub(aliases))
+        });
+        scored
+            .into_iter()
+            .take(count)
+            .map(|(_, id)| &self.keys[id as usize])
+            .map(|key| Match { zone: &self.names[key.zone as usize], alias: key.alias.as_deref() })
+            .collect()
+    }
+
+    /// Collects the IDs of the keys which share a trigram or a word prefix with the
+    /// (case-folded) `query` in ascending order.
+    fn candidates(&self, query: &str) -> Vec<u32> {
+        let mut hit = vec![false; self.keys.len()];
+        for trigram in trigrams(query) {
+            for &id in self.postings.get(&trigram).into_iter().flatten() {
+                hit[id as usize] = true;
+            }
+        }
+
+        for prefix in score::words(query) {
+            let start = self.segments.partition_point(|(segment, _)| **segment < *prefix);
+            let matches = self.segments[start..]
+                .iter()
+                .take_while(|(segment, _)| segment.starts_with(prefix));
+            for &(_, id) in matches {
+                hit[id as usize] = true;
+            }
+        }
+
+        (0..).zip(hit).filter_map(|(id, hit)| hit.then_some(id)).collect()
+    }
+}
+
+/// Canonical zones (i.e., those listed for some country or city) in order of relevance,
+/// which follows the dataset (i.e., the most populous zones of each country first).
+pub fn zones() -> Vec<&'static str> {
+    let mut seen = hashbrown::HashSet::new();
+    aliases(ALIASES).map(|(_, zone)| zone).filter(|zone| seen.insert(*zone)).collect()
+}
+
+/// Parses the generated dataset into pairs of an alias and the name of its zone.
+fn aliases(dataset: &str) -> impl Iterator<Item = (&str, &str)> {
+    dataset
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('\t'))
+        .flat_map(|(alias, zones)| zones.split(' ').map(move |zone| (alias, zone)))
+}
+
+/// Iterates over the trigrams of the `text`.
+fn trigrams(text: &str) -> impl Iterator<Item = Trigram> + '_ {
+    text.as_bytes().windows(3).map(|window| [window[0], window[1], window[2]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Index, Match, Scorer as _};
+
+    /// Scores the `zone` by its best key.
+    fn score(index: &Index, zone: &str, query: &str) -> f64 {
+        let zone = index.names.iter().position(|name| **name == *zone).unwrap() as u32;
+        let keys = index.keys.iter().filter(|key| key.zone == zone);
+        let scores = keys.map(|key| super::Segmented::DEFAULT.score(&key.folded, query));
+        scores.fold(f64::MIN, f64::max)
+    }
+
+    /// Scores every zone without narrowing down the candidates. Since ties may be broken
+    /// differently, only the scores of the top `count` are returned.
+    fn scan(index: &Index, query: &str, count: usize) -> Vec<f64> {
+        let query = super::score::fold(query);
+        let mut scores: Vec<_> =
+            index.names.iter().map(|zone| score(index, zone, &query)).collect();
+        scores.sort_by_key(|&score| core::cmp::Reverse(super::TotalDouble(score)));
+        scores.truncate(count);
+        scores
+    }
+
+    fn search<'a>(index: &'a Index, query: &str, count: usize) -> Vec<&'a str> {
+        index.search(query, count).into_iter().map(|found| found.zone).collect()
+    }
+
+    /// Scores the zones found by the search.
+    fn search_scores(index: &Index, query: &str, count: usize) -> Vec<f64> {
+        let folded = super::score::fold(query);
+        let found = search(index, query, count);
+        found.into_iter().map(|zone| score(index, zone, &folded)).collect()
+    }
+
+    #[test]
+    fn search_agrees_with_full_scan() {
+        let index = &*super::TIMEZONES;
+        for query in ["Asia/Ma", "Europe/Ber", "Etc/GMT+8", "UTC"] {
+            assert_eq!(search_scores(index, query, 5), scan(index, query, 5), "{query}");
+        }
+
+        // Keys that share no trigram or word prefix with the query may still score well by
+        // coincidence (e.g., `Europe/Malta` for `Manila`), so only the best match must agree.
+        for query in ["Manila", "new york", "los angeles", "Tokio", "Londn", "Germany"] {
+            assert_eq!(search_scores(index, query, 1), scan(index, query, 1), "{query}");
+        }
+    }
+
+    #[test]
+    fn baseline_queries() {
+        // The expectations of the full Jaro-Winkler scan which preceded the index, whose
+        // partial sort placed `Asia/Muscat` before the equally similar `Asia/Manila`.
+        let names = jiff::tz::db().available().map(|tz| tz.as_str().into());
+        let index = Index::new(names, []);
+        let search = |query| -> Vec<_> {
+            let found = index.search_with(query, 5, &super::score::JaroWinkler, None);
+            found.into_iter().map(|found| found.zone).collect()
+        };
+        assert_eq!(
+            search("Asia/Ma"),
+            ["Asia/Macao", "Asia/Macau", "Asia/Muscat", "Asia/Manila", "Asia/Magadan"]
+        );
+    }
+
+    #[test]
+    fn search_everything() {
+        let aliases = [("Foobar", "A/Foo"), ("Bar", "B/Bar"), ("Baz", "C/Baz")];
+        let index = Index::new(["B/Bar", "A/Foo", "A/Foo"].map(Box::from), aliases);
+        assert_eq!(index.len(), 2);
+        assert_eq!(search(&index, "foo", 5), ["A/Foo", "B/Bar"]);
+        assert_eq!(search(&index, "B/BAR", 2), ["B/Bar", "A/Foo"]);
+        assert!(index.search("", 0).is_empty());
+
+        // Names win ties against aliases, and each zone appears once.
+        let found = index.search("bar", 5);
+        assert_eq!(found[0], Match { zone: "B/Bar", alias: None });
+        assert_eq!(found.len(), 2);
+        assert_eq!(index.search("foobar", 1), [Match { zone: "A/Foo", alias: Some("Foobar") }]);
+    }
+}
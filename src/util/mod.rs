@@ -1,66 +1,248 @@
+pub mod abbr;
 pub mod float;
+pub mod index;
+pub mod popularity;
+pub mod score;
 pub mod sort;
+pub mod tz;
 
-/// Compares a `query` string to a list of supported IANA timezones. The return value
-/// is a vector of string slices, where the first `count` elements are an (unspecified)
-/// ordering of the top `count` autocompletion results.
-///
-/// # Panic
-/// Panics if `count` is greater than or equal to the number of timezones in the system database.
-pub fn autocomplete_tz(query: &str, count: usize) -> Box<[Box<str>]> {
-    use core::cmp::Reverse;
-    use float::TotalDouble;
-    use std::sync::LazyLock;
-
-    static CACHED_TIMEZONES: LazyLock<Box<[Box<str>]>> = LazyLock::new(|| {
-        jiff::tz::db()
-            .available()
-            .map(|tz| tz.as_str().into())
-            .collect::<Vec<_>>()
-            .into_boxed_slice()
-    });
-
-    let mut names = CACHED_TIMEZONES.clone();
-    let mut cache = hashbrown::HashMap::with_capacity(32);
-
-    sort::top_n_by_key(&mut names, count, |tz| {
-        if !cache.contains_key(tz) {
-            let score = textdistance::str::jaro_winkler(tz, query);
-            let tz = tz.clone();
-            unsafe { cache.insert_unique_unchecked(tz, score) };
+/// Formats a UTC offset as `UTC±HH:MM` (with seconds only when nonzero).
+pub fn format_offset(offset: jiff::tz::Offset) -> String {
+    let total = offset.seconds();
+    let sign = if total < 0 { '-' } else { '+' };
+    let total = total.unsigned_abs();
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if seconds == 0 {
+        format!("UTC{sign}{hours:02}:{minutes:02}")
+    } else {
+        format!("UTC{sign}{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Describes the offset of the `tz` at the instant `at` as `UTC±HH:MM (ABBR)`. Numeric
+/// abbreviations (e.g., `+08`) are omitted since they merely repeat the offset.
+pub fn describe_offset(tz: &jiff::tz::TimeZone, at: jiff::Timestamp) -> String {
+    let info = tz.to_offset_info(at);
+    let offset = format_offset(info.offset());
+    let abbreviation = info.abbreviation();
+    if abbreviation.starts_with(['+', '-']) || abbreviation.is_empty() {
+        offset
+    } else {
+        format!("{offset} ({abbreviation})")
+    }
+}
+
+/// Queries of at most this many characters are considered short, for which the zones of
+/// the [`Personalization`] are suggested first.
+const SHORT_QUERY: usize = 2;
+
+/// Zones which the user has chosen before.
+#[derive(Clone, Copy, Default)]
+pub struct Personalization<'a> {
+    /// Zones pinned by the user, which precede the recent ones.
+    pub favorites: &'a [Box<str>],
+    /// Zones most recently submitted by the user (most recent first).
+    pub recent: &'a [Box<str>],
+}
+
+/// Parameters of [`autocomplete_tz`] besides the query.
+#[derive(Clone, Copy)]
+pub struct Search<'a> {
+    /// Maximum number of results.
+    pub count: usize,
+    pub scorer: &'a dyn score::Scorer,
+    pub personal: Personalization<'a>,
+    /// Boosts of the popular zones (if any).
+    pub boosts: Option<&'a popularity::Boosts>,
+}
+
+impl Search<'_> {
+    /// Searches for (up to) `count` zones by the default scorer without any
+    /// personalization or boosts.
+    pub const fn new(count: usize) -> Self {
+        Self {
+            count,
+            scorer: &score::Segmented::DEFAULT,
+            personal: Personalization { favorites: &[], recent: &[] },
+            boosts: None,
+        }
+    }
+}
+
+/// Compares a `query` string to the supported IANA timezones and their aliases (e.g.,
+/// countries and cities). Returns (up to) the top `count` autocompletion results in
+/// descending order of similarity by the `scorer` (raised by the `boosts` of popular
+/// zones). For empty or short queries, the zones of the user's `personal`ization (which
+/// start with the query) come first.
+pub fn autocomplete_tz(
+    query: &str,
+    Search { count, scorer, personal, boosts }: Search,
+) -> Vec<index::Match<'static>> {
+    let index = &*index::TIMEZONES;
+    let folded = score::fold(query);
+    let mut found = Vec::new();
+    if folded.chars().count() <= SHORT_QUERY {
+        let personal = personal.favorites.iter().chain(personal.recent);
+        for zone in personal.filter_map(|zone| index.name(zone)) {
+            let name = score::fold(zone);
+            let matches = name.starts_with(&folded)
+                || score::words(&name).any(|word| word.starts_with(&folded));
+            if matches && found.iter().all(|other: &index::Match| other.zone != zone) {
+                found.push(index::Match { zone, alias: None });
+            }
         }
-        Reverse(TotalDouble(cache[tz]))
-    });
+        found.truncate(count);
+    }
 
-    names
+    // The fuzzy matches fill the rest without repeating the personal zones.
+    let rest = index.search_with(query, count, scorer, boosts).into_iter();
+    let rest = rest.filter(|other| found.iter().all(|found| found.zone != other.zone));
+    let rest: Vec<_> = rest.take(count - found.len()).collect();
+    found.extend(rest);
+    found
 }
 
 #[cfg(test)]
 mod tests {
+    /// Golden corpus of queries along with their expected leading autocompletions.
+    const GOLDEN: &str = include_str!("autocomplete.golden");
+
+    /// Renders the golden corpus from the current ranking, keeping as many results per
+    /// query as are listed.
+    fn render() -> String {
+        let mut golden = String::new();
+        for line in GOLDEN.lines() {
+            let Some((query, expected)) = line.split_once(" => ") else {
+                // Comments and blank lines are kept as is.
+                golden.push_str(line);
+                golden.push('\n');
+                continue;
+            };
+            let names: Vec<_> = super::autocomplete_tz(query, super::Search::new(5))
+                .into_iter()
+                .take(expected.split(", ").count())
+                .map(|found| match found.alias {
+                    Some(alias) => format!("{} ({alias})", found.zone),
+                    None => String::from(found.zone),
+                })
+                .collect();
+            golden.push_str(&format!("{query} => {}\n", names.join(", ")));
+        }
+        golden
+    }
+
+    /// Ranking changes must be reviewed through the golden corpus, which is regenerated by
+    /// running this test with `UPDATE_GOLDEN=1`.
     #[test]
     fn autocomplete_queries() {
-        let names = super::autocomplete_tz("Asia/Ma", 5);
-        assert_eq!(
-            &names[..5],
-            &[
-                "Asia/Macao".into(),
-                "Asia/Macau".into(),
-                "Asia/Muscat".into(),
-                "Asia/Manila".into(),
-                "Asia/Magadan".into()
-            ]
-        );
-
-        let names = super::autocomplete_tz("Asia/Man", 5);
-        assert_eq!(
-            &names[..5],
-            &[
-                "Asia/Manila".into(),
-                "Asia/Magadan".into(),
-                "Asia/Amman".into(),
-                "Asia/Macao".into(),
-                "Asia/Macau".into()
-            ]
-        );
+        let golden = render();
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/util/autocomplete.golden");
+            std::fs::write(path, golden).unwrap();
+            return;
+        }
+
+        let mismatches: Vec<_> = GOLDEN
+            .lines()
+            .zip(golden.lines())
+            .filter(|(expected, actual)| expected != actual)
+            .map(|(expected, actual)| format!("- {expected}\n+ {actual}"))
+            .collect();
+        assert!(mismatches.is_empty(), "ranking changed:\n{}", mismatches.join("\n"));
+    }
+
+    #[test]
+    fn personalized_queries() {
+        let favorites = ["Asia/Manila"].map(Box::from);
+        let recent =
+            ["Europe/Berlin", "Asia/Manila", "Mars/Olympus", "America/New_York"].map(Box::from);
+        let personal = super::Personalization { favorites: &favorites, recent: &recent };
+        let zones = |query: &str, count: usize| -> Vec<&str> {
+            let found = super::autocomplete_tz(
+                query,
+                super::Search { personal, ..super::Search::new(count) },
+            );
+            found.into_iter().map(|found| found.zone).collect()
+        };
+
+        // Unknown zones are skipped and favorites are not repeated among the recent ones.
+        assert_eq!(zones("", 5)[..3], ["Asia/Manila", "Europe/Berlin", "America/New_York"]);
+        assert_eq!(zones("", 2), ["Asia/Manila", "Europe/Berlin"]);
+
+        // Short queries only keep the personal zones with a matching word.
+        assert_eq!(zones("m", 3)[0], "Asia/Manila");
+        assert_eq!(zones("n", 3)[0], "America/New_York");
+        let found = zones("e", 5);
+        assert_eq!(found[0], "Europe/Berlin");
+        assert_eq!(found.iter().filter(|zone| **zone == "Europe/Berlin").count(), 1);
+
+        // Longer queries are ranked as usual.
+        assert_eq!(zones("Berl", 1), ["Europe/Berlin"]);
+        assert_eq!(zones("Tokyo", 1), ["Asia/Tokyo"]);
+    }
+
+    #[test]
+    fn popular_queries() {
+        use super::popularity::{Popularity, Prior};
+        let (now, prior) = (jiff::Timestamp::UNIX_EPOCH, Prior::DEFAULT);
+        let top = |query: &str, popularity: &Popularity| {
+            let boosts = popularity.boosts(query, now, &prior);
+            super::autocomplete_tz(
+                query,
+                super::Search { boosts: Some(&boosts), ..super::Search::new(5) },
+            )[0]
+            .zone
+        };
+
+        let mut popularity = Popularity::default();
+        assert_ne!(top("Ame", &popularity), "America/New_York");
+        for _ in 0..3 {
+            popularity.record(["A", "Am", "Ame"], "America/New_York", now, &prior);
+        }
+        assert_eq!(top("Ame", &popularity), "America/New_York");
+    }
+
+    /// Decorates each character of the `plain` query according to the `choices` with case
+    /// changes, full-width forms, diacritics, and alternative whitespace.
+    fn decorate(plain: &str, choices: &[u8]) -> String {
+        let mut decorated = String::new();
+        for (c, choice) in plain.chars().zip(choices.iter().cycle()) {
+            match (c, choice % 4) {
+                (' ', 0) => decorated.push('_'),
+                (' ', 1) => decorated.push_str("  "),
+                (' ', 2) => decorated.push('\u{3000}'),
+                (c, 1) => decorated.push(c.to_ascii_uppercase()),
+                // Full-width forms of printable ASCII are offset by a constant.
+                (c, 2) if c.is_ascii_graphic() => {
+                    decorated.extend(char::from_u32(c as u32 + 0xFEE0));
+                }
+                (c, 3) if c.is_ascii_alphabetic() => {
+                    decorated.push(c);
+                    decorated.push('\u{301}');
+                }
+                (c, _) => decorated.push(c),
+            }
+        }
+        decorated
+    }
+
+    proptest::proptest! {
+        #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+        #[test]
+        fn normalized_queries_rank_identically(
+            plain in proptest::sample::select(&[
+                "sao paulo", "zurich", "ciudad de mexico", "tokyo", "america/los angeles",
+                "new york", "reunion", "etc/gmt+8", "cote divoire", "philippines",
+            ][..]),
+            choices in proptest::collection::vec(0u8..4, 1..8),
+        ) {
+            let decorated = decorate(plain, &choices);
+            let rank = |query: &str| {
+                let found = super::autocomplete_tz(query, super::Search::new(5));
+                found.into_iter().map(|found| found.zone).collect::<Vec<_>>()
+            };
+            proptest::prop_assert_eq!(rank(&decorated), rank(plain), "{}", decorated);
+        }
     }
 }
@@ -0,0 +1,267 @@
+//! Popularity prior of the timezones learned from what users actually pick. The queries
+//! typed while autocompleting are paired with the zone eventually submitted, which are
+//! then aggregated per query prefix as exponentially decaying counts.
+
+use super::score;
+use jiff::{SignedDuration, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    io::Write as _,
+    path::PathBuf,
+    sync::{Mutex, PoisonError},
+};
+
+/// Only prefixes of up to this many characters are aggregated.
+const MAX_PREFIX: usize = 16;
+
+/// Maximum number of zones kept per prefix.
+const MAX_ZONES: usize = 8;
+
+/// Decayed counts below this weight are forgotten.
+const MIN_WEIGHT: f64 = 0.05;
+
+/// Autocompletion sessions without a submission for this long are abandoned.
+const SESSION_TIMEOUT: SignedDuration = SignedDuration::from_mins(15);
+
+/// Maximum number of (the latest) queries kept per session.
+const MAX_QUERIES: usize = 32;
+
+/// Configuration of the popularity prior.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Prior {
+    /// Weight of the prior relative to the similarity scores (see [`super::score`]).
+    pub weight: f64,
+    /// Duration after which each pick counts half as much.
+    pub half_life: SignedDuration,
+}
+
+impl Prior {
+    pub const DEFAULT: Self = Self { weight: 0.3, half_life: SignedDuration::from_hours(30 * 24) };
+
+    /// Decays the `weight` last updated at `updated` until `now`.
+    fn decay(&self, weight: f64, updated: Timestamp, now: Timestamp) -> f64 {
+        let elapsed = now.duration_since(updated).as_secs_f64().max(0.0);
+        let half_life = self.half_life.as_secs_f64();
+        if half_life <= 0.0 { weight } else { weight * 0.5f64.powf(elapsed / half_life) }
+    }
+}
+
+impl Default for Prior {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Decaying count of the picks of a zone for some prefix.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Pick {
+    pub zone: Box<str>,
+    pub weight: f64,
+    pub updated: Timestamp,
+}
+
+/// Picks keyed by the (case-folded) query prefix.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Popularity {
+    picks: HashMap<Box<str>, Vec<Pick>>,
+}
+
+impl Popularity {
+    /// Records that the `zone` was picked after typing the `queries`. Each distinct prefix
+    /// of the queries counts once.
+    pub fn record<'q>(
+        &mut self,
+        queries: impl IntoIterator<Item = &'q str>,
+        zone: &str,
+        now: Timestamp,
+        prior: &Prior,
+    ) {
+        let mut prefixes = Vec::new();
+        for query in queries {
+            let query = score::fold(query);
+            let ends = query.char_indices().map(|(start, c)| start + c.len_utf8());
+            prefixes.extend(ends.take(MAX_PREFIX).map(|end| Box::<str>::from(&query[..end])));
+        }
+        prefixes.sort_unstable();
+        prefixes.dedup();
+
+        for prefix in prefixes {
+            let picks = self.picks.entry(prefix).or_default();
+            for pick in picks.iter_mut() {
+                pick.weight = prior.decay(pick.weight, pick.updated, now);
+                pick.updated = now;
+            }
+            match picks.iter_mut().find(|pick| *pick.zone == *zone) {
+                Some(pick) => pick.weight += 1.0,
+                None => picks.push(Pick { zone: zone.into(), weight: 1.0, updated: now }),
+            }
+            picks.retain(|pick| pick.weight >= MIN_WEIGHT);
+            picks.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+            picks.truncate(MAX_ZONES);
+        }
+    }
+
+    /// Computes the boosts of the zones picked for the `query` (as is), which are their
+    /// shares of the decayed picks scaled by the weight of the `prior`. A pseudocount of
+    /// one keeps a single pick from claiming the whole share.
+    pub fn boosts(&self, query: &str, now: Timestamp, prior: &Prior) -> Boosts {
+        let Some(picks) = self.picks.get(score::fold(query).as_str()) else {
+            return Boosts::default();
+        };
+        let weights: Vec<_> =
+            picks.iter().map(|pick| (pick, prior.decay(pick.weight, pick.updated, now))).collect();
+        let total = 1.0 + weights.iter().map(|(_, weight)| weight).sum::<f64>();
+        Boosts(
+            weights
+                .into_iter()
+                .map(|(pick, weight)| (pick.zone.clone(), prior.weight * weight / total))
+                .collect(),
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.picks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.picks.is_empty()
+    }
+}
+
+/// Additive boosts of the scores of some zones.
+#[derive(Clone, Debug, Default)]
+pub struct Boosts(HashMap<Box<str>, f64>);
+
+impl Boosts {
+    pub fn get(&self, zone: &str) -> f64 {
+        self.0.get(zone).copied().unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A logged pick for offline evaluation (see `cargo run --example evaluate`).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Logged {
+    pub at: Timestamp,
+    /// Queries typed while autocompleting in chronological order.
+    pub queries: Vec<Box<str>>,
+    pub zone: Box<str>,
+}
+
+/// Autocompletion session of some user.
+struct Session {
+    updated: Timestamp,
+    queries: Vec<Box<str>>,
+}
+
+/// Tracks the autocompletion sessions of the users in memory until their submissions.
+#[derive(Default)]
+pub struct Learner {
+    pub prior: Prior,
+    sessions: Mutex<HashMap<u64, Session>>,
+    /// Path of the JSON Lines file into which the picks are logged (if any).
+    log: Option<PathBuf>,
+}
+
+impl Learner {
+    pub fn new(prior: Prior, log: Option<PathBuf>) -> Self {
+        Self { prior, sessions: Mutex::default(), log }
+    }
+
+    /// Observes the `query` typed by the `user` while autocompleting.
+    pub fn observe(&self, user: u64, query: &str, now: Timestamp) {
+        let query = query.trim();
+        let mut sessions = self.sessions.lock().unwrap_or_else(PoisonError::into_inner);
+        sessions.retain(|_, session| now.duration_since(session.updated) < SESSION_TIMEOUT);
+        let session = sessions.entry(user).or_insert(Session { updated: now, queries: Vec::new() });
+        session.updated = now;
+        if !query.is_empty() && session.queries.last().is_none_or(|last| **last != *query) {
+            // Keep the latest queries since they are the closest to the pick.
+            if session.queries.len() >= MAX_QUERIES {
+                session.queries.remove(0);
+            }
+            session.queries.push(query.into());
+        }
+    }
+
+    /// Concludes the session of the `user` with the submission of the `zone`. Returns the
+    /// queries of the session (if any), which are also logged.
+    pub fn conclude(&self, user: u64, zone: &str, now: Timestamp) -> Option<Vec<Box<str>>> {
+        let session = self.sessions.lock().unwrap_or_else(PoisonError::into_inner).remove(&user)?;
+        if now.duration_since(session.updated) >= SESSION_TIMEOUT || session.queries.is_empty() {
+            return None;
+        }
+
+        if let Some(path) = &self.log {
+            let logged = Logged { at: now, queries: session.queries.clone(), zone: zone.into() };
+            let result =
+                serde_json::to_vec(&logged).map_err(std::io::Error::other).and_then(|mut line| {
+                    line.push(b'\n');
+                    let mut file =
+                        std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+                    file.write_all(&line)
+                });
+            if let Err(err) = result {
+                log::error!("Failed to log timezone pick: {err}.");
+            }
+        }
+        Some(session.queries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Learner, Popularity, Prior};
+    use jiff::{SignedDuration, Timestamp};
+
+    #[test]
+    fn boosts_decay() {
+        let prior = Prior { weight: 1.0, half_life: SignedDuration::from_hours(24) };
+        let now = Timestamp::UNIX_EPOCH;
+        let mut popularity = Popularity::default();
+        popularity.record(["Ame", "America/N"], "America/New_York", now, &prior);
+        popularity.record(["am"], "America/Adak", now, &prior);
+        assert!(popularity.boosts("x", now, &prior).is_empty());
+
+        // A single pick only claims half of the share due to the pseudocount.
+        let boosts = popularity.boosts("AME", now, &prior);
+        assert_eq!(boosts.get("America/New_York"), 0.5);
+        assert_eq!(boosts.get("America/Adak"), 0.0);
+        let boosts = popularity.boosts("am", now, &prior);
+        assert_eq!(boosts.get("America/New_York"), boosts.get("America/Adak"));
+
+        // Older picks count less than newer ones.
+        let later = now + SignedDuration::from_hours(24);
+        popularity.record(["am"], "America/Adak", later, &prior);
+        let boosts = popularity.boosts("am", later, &prior);
+        assert_eq!(boosts.get("America/Adak"), 1.5 / 3.0);
+        assert_eq!(boosts.get("America/New_York"), 0.5 / 3.0);
+
+        // Prefixes are capped so that long queries do not bloat the store.
+        popularity.record(["x".repeat(50).as_str()], "UTC", now, &prior);
+        assert!(!popularity.boosts(&"x".repeat(16), now, &prior).is_empty());
+        assert!(popularity.boosts(&"x".repeat(17), now, &prior).is_empty());
+    }
+
+    #[test]
+    fn sessions_expire() {
+        let learner = Learner::default();
+        let now = Timestamp::UNIX_EPOCH;
+        for query in ["A", "Am", "Am", " ", "Ame"] {
+            learner.observe(1, query, now);
+        }
+        assert_eq!(
+            learner.conclude(1, "America/New_York", now).unwrap(),
+            ["A", "Am", "Ame"].map(Box::from)
+        );
+        assert!(learner.conclude(1, "America/New_York", now).is_none());
+
+        learner.observe(2, "Asia", now);
+        assert!(learner.conclude(2, "Asia/Tokyo", now + SignedDuration::from_mins(15)).is_none());
+    }
+}
@@ -0,0 +1,320 @@
+//! Scoring model for matching queries against the names of timezones. A name is matched
+//! as a whole, segment by segment (e.g., `Manila` in `Asia/Manila`), and word by word
+//! (e.g., `Angeles` in `America/Los_Angeles`). Both sides are normalized beforehand (see
+//! [`fold`]) so that `los angeles` matches `Los_Angeles` and `sao paulo` matches `São Paulo`.
+
+/// Similarity of a (normalized) target to a (normalized) query, where higher is more
+/// similar. Scorers are stateless so that they may be shared across requests.
+pub trait Scorer: Send + Sync {
+    fn score(&self, target: &str, query: &str) -> f64;
+}
+
+/// Jaro-Winkler similarity, which favors common prefixes.
+pub struct JaroWinkler;
+
+impl Scorer for JaroWinkler {
+    fn score(&self, target: &str, query: &str) -> f64 {
+        textdistance::str::jaro_winkler(target, query)
+    }
+}
+
+/// Damerau-Levenshtein similarity (i.e., one minus the normalized edit distance), which
+/// tolerates transposed characters (e.g., `tokoy` for `tokyo`).
+pub struct DamerauLevenshtein;
+
+impl Scorer for DamerauLevenshtein {
+    fn score(&self, target: &str, query: &str) -> f64 {
+        1.0 - textdistance::nstr::damerau_levenshtein(target, query)
+    }
+}
+
+/// Subsequence matching in the style of fzf, where every character of the query must
+/// appear in order in the target (e.g., `dst` in `dst-alerts`). Consecutive characters and
+/// those which start a word earn bonuses whereas longer targets are slightly penalized.
+/// Targets which do not match score zero and the query itself scores one.
+pub struct Subsequence;
+
+impl Subsequence {
+    /// Greedily matches the `query` in the `target`. Returns the points earned (if any).
+    fn points(target: &[char], query: &[char]) -> Option<f64> {
+        let (mut points, mut next, mut previous) = (0.0, 0, None);
+        for &c in query {
+            let index = next + target[next..].iter().position(|&other| other == c)?;
+            points += 1.0;
+            if index > 0 && previous == Some(index - 1) {
+                points += 1.0;
+            }
+            if index == 0 || !target[index - 1].is_alphanumeric() {
+                points += 1.0;
+            }
+            (next, previous) = (index + 1, Some(index));
+        }
+        Some(points)
+    }
+}
+
+impl Scorer for Subsequence {
+    fn score(&self, target: &str, query: &str) -> f64 {
+        let target: Vec<_> = target.chars().collect();
+        let query: Vec<_> = query.chars().collect();
+        if query.is_empty() {
+            return 1.0;
+        }
+        let (Some(points), Some(ideal)) =
+            (Self::points(&target, &query), Self::points(&query, &query))
+        else {
+            return 0.0;
+        };
+        let coverage = query.len() as f64 / target.len() as f64;
+        (0.8 * points / ideal + 0.2 * coverage).min(1.0)
+    }
+}
+
+/// Weighted sum of several scorers (e.g., to blend typo tolerance with prefix matching).
+pub struct Weighted<'a>(pub &'a [(f64, &'a dyn Scorer)]);
+
+impl Scorer for Weighted<'_> {
+    fn score(&self, target: &str, query: &str) -> f64 {
+        self.0.iter().map(|(weight, scorer)| weight * scorer.score(target, query)).sum()
+    }
+}
+
+/// Relative weights of the signals which make up a [`Segmented`] score.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Weights {
+    /// Bonus when the target starts with the query.
+    pub prefix: f64,
+    /// Bonus when the target contains the query anywhere (including as a prefix).
+    pub substring: f64,
+    /// Weight of the similarity between the target and the query.
+    pub similarity: f64,
+    /// Factor of the signals of single words, which is slightly below one so that a whole
+    /// segment wins over a mere word within another (e.g., `Etc/GMT` over `Etc/GMT-10`).
+    pub word: f64,
+}
+
+impl Weights {
+    pub const DEFAULT: Self = Self { prefix: 0.5, substring: 0.25, similarity: 1.0, word: 0.95 };
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Scores names (e.g., of timezones) as a whole, segment by segment, and word by word
+/// (see the module documentation) by the `similarity` along with the bonuses of the
+/// `weights`. The best match wins.
+pub struct Segmented<S> {
+    pub weights: Weights,
+    pub similarity: S,
+}
+
+impl Segmented<JaroWinkler> {
+    pub const DEFAULT: Self = Self { weights: Weights::DEFAULT, similarity: JaroWinkler };
+}
+
+impl<S: Scorer> Segmented<S> {
+    fn signal(&self, target: &str, query: &str) -> f64 {
+        // Targets shorter than the query (e.g., country codes) can only cover part of it,
+        // so their similarity is scaled down accordingly. A single missing character is
+        // tolerated as a typo (e.g., `manila` for `manilla`).
+        let (length, expected) = (target.chars().count() + 1, query.chars().count());
+        let coverage = if length < expected { length as f64 / expected as f64 } else { 1.0 };
+        let weights = &self.weights;
+        let mut score = weights.similarity * coverage * self.similarity.score(target, query);
+        if target.starts_with(query) {
+            score += weights.prefix;
+        }
+        if target.contains(query) {
+            score += weights.substring;
+        }
+        score
+    }
+}
+
+impl<S: Scorer> Scorer for Segmented<S> {
+    fn score(&self, name: &str, query: &str) -> f64 {
+        let segments = core::iter::once(name).chain(name.split('/'));
+        let segments = segments.map(|target| self.signal(target, query));
+        let words = words(name).map(|target| self.weights.word * self.signal(target, query));
+        segments.chain(words).fold(0.0, f64::max)
+    }
+}
+
+/// Ranks the `items` by the similarity of their `key`s to the `query` according to the
+/// `scorer`, keeping (up to) the top `count` in descending order. Both sides are normalized
+/// beforehand. Items which do not match at all are dropped unless the query is empty, in
+/// which case every item is kept in order. Ties are broken by the order of the items.
+pub fn rank<T>(
+    items: impl IntoIterator<Item = T>,
+    key: impl Fn(&T) -> &str,
+    query: &str,
+    scorer: &dyn Scorer,
+    count: usize,
+) -> Vec<T> {
+    use super::{float::TotalDouble, sort::top_n_by_key};
+    use core::cmp::Reverse;
+
+    let query = fold(query);
+    let mut scored: Vec<_> = items
+        .into_iter()
+        .enumerate()
+        .map(|(id, item)| {
+            let score =
+                if query.is_empty() { 0.0 } else { scorer.score(&fold(key(&item)), &query) };
+            (score, id, item)
+        })
+        .filter(|&(score, ..)| query.is_empty() || score > 0.0)
+        .collect();
+    top_n_by_key(&mut scored, count, |&(score, id, _)| (Reverse(TotalDouble(score)), id));
+    scored.truncate(count);
+    scored.into_iter().map(|(.., item)| item).collect()
+}
+
+/// Normalizes the `text` for comparison. It is decomposed into NFKD (which also maps
+/// full-width forms to their ASCII counterparts), stripped of combining marks (i.e.,
+/// diacritics), and case-folded. Apostrophes are dropped (e.g., `N'Djamena` as `ndjamena`)
+/// whereas runs of whitespace, underscores, and other punctuation collapse into a single
+/// space. Only slashes, hyphens, plus signs, and parentheses are kept as delimiters.
+pub fn fold(text: &str) -> String {
+    use unicode_normalization::{UnicodeNormalization as _, char::is_combining_mark};
+    let mut folded = String::with_capacity(text.len());
+    let mut space = false;
+    for c in text.nfkd().filter(|&c| !is_combining_mark(c)).flat_map(char::to_lowercase) {
+        let expansion = match c {
+            '\'' | '’' | 'ʼ' | '`' => continue,
+            'ß' => "ss",
+            'æ' => "ae",
+            'œ' => "oe",
+            'ø' => "o",
+            'ł' => "l",
+            'đ' | 'ð' => "d",
+            'ı' => "i",
+            '/' | '-' | '+' | '(' | ')' => {
+                // Delimiters absorb the surrounding whitespace.
+                if folded.ends_with(' ') {
+                    folded.pop();
+                }
+                folded.push(c);
+                space = false;
+                continue;
+            }
+            c if c.is_alphanumeric() => {
+                if space && !folded.is_empty() && !folded.ends_with(['/', '-', '+', '(', ')']) {
+                    folded.push(' ');
+                }
+                space = false;
+                folded.push(c);
+                continue;
+            }
+            _ => {
+                space = true;
+                continue;
+            }
+        };
+        if space && !folded.is_empty() && !folded.ends_with(['/', '-', '+', '(', ')']) {
+            folded.push(' ');
+        }
+        space = false;
+        folded.push_str(expansion);
+    }
+    folded
+}
+
+/// Iterates over the nonempty words of the (case-folded) `text`, which are delimited by
+/// slashes, hyphens, spaces, and parentheses (e.g., `uk` in `britain (uk)`).
+pub fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(['/', '-', ' ', '(', ')']).filter(|word| !word.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DamerauLevenshtein, JaroWinkler, Scorer, Segmented, Subsequence, Weighted, Weights, fold,
+    };
+
+    #[test]
+    fn segments_and_words() {
+        let weights = Weights::DEFAULT;
+        let score = |weights: &Weights, name: &str, query: &str| {
+            Segmented { weights: *weights, similarity: JaroWinkler }.score(name, query)
+        };
+        assert_eq!(fold("  America/Los_Angeles "), "america/los angeles");
+
+        // An exact segment outweighs a merely similar whole name.
+        let query = fold("Manila");
+        assert!(score(&weights, "asia/manila", &query) > score(&weights, "asia/magadan", &query));
+
+        // Words within a segment count as well.
+        let query = fold("angeles");
+        assert!(
+            score(&weights, "america/los angeles", &query)
+                > score(&weights, "america/anchorage", &query)
+        );
+
+        // Without the bonuses, only the similarity remains.
+        let plain = Weights { prefix: 0.0, substring: 0.0, similarity: 1.0, word: 1.0 };
+        assert_eq!(score(&plain, "utc", "utc"), 1.0);
+        assert_eq!(score(&weights, "utc", "utc"), 1.75);
+
+        // Whole segments win over words within other segments.
+        let query = fold("GMT");
+        assert!(score(&weights, "etc/gmt", &query) > score(&weights, "etc/gmt-10", &query));
+
+        // Short targets do not match longer queries by similarity alone.
+        let query = fold("Tokyo");
+        assert!(score(&plain, "to", &query) < 0.6);
+    }
+
+    #[test]
+    fn scorers() {
+        assert_eq!(DamerauLevenshtein.score("tokyo", "tokyo"), 1.0);
+        assert_eq!(DamerauLevenshtein.score("tokyo", "tokoy"), 0.8);
+
+        // Subsequences favor consecutive characters and starts of words.
+        assert_eq!(Subsequence.score("epoch", "epoch"), 1.0);
+        assert_eq!(Subsequence.score("epoch", "hce"), 0.0);
+        assert!(Subsequence.score("dst-alerts", "dal") > Subsequence.score("dstalerts", "dal"));
+        assert!(Subsequence.score("help", "hl") > Subsequence.score("helpful", "hl"));
+        assert_eq!(Subsequence.score("anything", ""), 1.0);
+
+        let blend = Weighted(&[(0.5, &DamerauLevenshtein), (0.5, &Subsequence)]);
+        assert_eq!(blend.score("tokyo", "tokyo"), 1.0);
+        assert_eq!(blend.score("tokyo", "tokoy"), 0.4);
+    }
+
+    #[test]
+    fn ranking() {
+        let topics = ["epoch", "help", "info", "dst-alerts"];
+        let rank = |query, count| super::rank(topics, |topic| topic, query, &Subsequence, count);
+        assert_eq!(rank("", 10), topics);
+        assert_eq!(rank("", 2), ["epoch", "help"]);
+        assert_eq!(rank("HLP", 10), ["help"]);
+        assert_eq!(rank("e", 10), ["epoch", "help", "dst-alerts"]);
+        assert!(rank("xyz", 10).is_empty());
+        assert!(rank("e", 0).is_empty());
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(fold("São Paulo"), "sao paulo");
+        assert_eq!(fold("ＺＵＲＩＣＨ"), "zurich");
+        assert_eq!(fold("Ciudad  de\tMéxico"), "ciudad de mexico");
+        assert_eq!(fold("N'Djamena"), "ndjamena");
+        assert_eq!(fold("St. John’s"), "st johns");
+        assert_eq!(fold(" America / Los_Angeles "), "america/los angeles");
+        assert_eq!(fold("Etc/GMT+8"), "etc/gmt+8");
+        assert_eq!(fold("Straße"), "strasse");
+        assert_eq!(fold("?!"), "");
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn fold_is_idempotent(text in "\\PC*") {
+            let folded = fold(&text);
+            proptest::prop_assert_eq!(fold(&folded), folded);
+        }
+    }
+}
@@ -1,8 +1,6 @@
 /// Finds the top `count` elements `items` by the key `compare`. The items
-/// outside the range `0..count` are left in an unspecified order.
-///
-/// # Panic
-/// Panics when `count >= items.len()`.
+/// outside the range `0..count` are left in an unspecified order. If `count`
+/// covers every item, the whole slice is simply sorted.
 ///
 /// # Implementation
 /// The algorithm repeatedly performs a select operation (based on the
@@ -23,6 +21,11 @@ where
 {
     use core::cmp::Ordering::{Equal, Greater, Less};
 
+    if count >= items.len() {
+        items.sort_unstable_by_key(compare);
+        return;
+    }
+
     let mut haystack = &mut *items;
     let mut index = count;
 
@@ -55,4 +58,21 @@ mod tests {
         super::top_n_by_key(&mut items, 1, Clone::clone);
         assert_eq!(items, [1, 2, 3, 4, 5]);
     }
+
+    #[test]
+    fn partial_sort_of_everything() {
+        let mut items = [3, 1, 2];
+        super::top_n_by_key(&mut items, 3, Clone::clone);
+        assert_eq!(items, [1, 2, 3]);
+
+        let mut items = [3, 1, 2];
+        super::top_n_by_key(&mut items, 10, Clone::clone);
+        assert_eq!(items, [1, 2, 3]);
+
+        let mut items: [i32; 0] = [];
+        super::top_n_by_key(&mut items, 0, Clone::clone);
+
+        let mut items = [2, 1];
+        super::top_n_by_key(&mut items, 0, Clone::clone);
+    }
 }
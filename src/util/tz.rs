@@ -0,0 +1,170 @@
+//! Resolution of the timezones typed by users, which may be names from the IANA Time Zone
+//! Database (e.g., `Asia/Manila`), fixed offsets (e.g., `+05:30`, `UTC-8`, `GMT+2`), POSIX
+//! TZ strings (e.g., `EST5EDT,M3.2.0,M11.1.0`), or abbreviations (e.g., `PST`, `CEST`).
+
+use jiff::tz::{Offset, TimeZone};
+
+/// A resolved timezone along with its canonical name, which resolves to the same timezone
+/// again (e.g., when persisted or encoded into a custom ID).
+#[derive(Clone, Debug)]
+pub struct Zone {
+    pub name: Box<str>,
+    pub tz: TimeZone,
+}
+
+impl Zone {
+    pub fn utc() -> Self {
+        Self { name: "UTC".into(), tz: TimeZone::UTC }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Unresolved {
+    Unknown,
+    /// The abbreviation stands for different offsets in different zones. The candidates
+    /// are rendered as the name of a zone along with its offset for that abbreviation.
+    Ambiguous {
+        abbreviation: Box<str>,
+        candidates: Box<[Box<str>]>,
+    },
+}
+
+/// Resolves the `text` into a timezone. Fixed offsets take precedence over names since
+/// `GMT+2` would otherwise be read in the inverted sense of `Etc/GMT+2`.
+pub fn resolve(text: &str) -> Result<Zone, Unresolved> {
+    let text = text.trim();
+    if let Some(offset) = parse_offset(text) {
+        let name = super::format_offset(offset).into();
+        return Ok(Zone { name, tz: TimeZone::fixed(offset) });
+    }
+
+    if let Ok(tz) = TimeZone::get(text) {
+        let name = tz.iana_name().unwrap_or(text).into();
+        return Ok(Zone { name, tz });
+    }
+
+    let usages = super::abbr::RECENT.get().map(|recent| recent.get(text)).unwrap_or_default();
+    if let Some(first) = usages.first() {
+        if usages.iter().all(|usage| usage.offset == first.offset) {
+            let name = super::format_offset(first.offset).into();
+            return Ok(Zone { name, tz: TimeZone::fixed(first.offset) });
+        }
+
+        // List one zone per distinct offset.
+        let mut offsets = Vec::new();
+        let candidates = usages
+            .iter()
+            .filter(|usage| {
+                let unseen = !offsets.contains(&usage.offset);
+                offsets.push(usage.offset);
+                unseen
+            })
+            .map(|usage| format!("{} ({})", usage.zone, super::format_offset(usage.offset)).into())
+            .collect();
+        let abbreviation = text.to_ascii_uppercase().into();
+        return Err(Unresolved::Ambiguous { abbreviation, candidates });
+    }
+
+    // POSIX TZ strings always contain the offset of standard time after its abbreviation.
+    if text.contains(|c: char| c.is_ascii_digit())
+        && let Ok(tz) = TimeZone::posix(text)
+    {
+        return Ok(Zone { name: text.into(), tz });
+    }
+
+    Err(Unresolved::Unknown)
+}
+
+/// Parses a fixed offset from UTC with an optional `UTC` or `GMT` prefix. The hours may
+/// be followed by the minutes (and the seconds) with or without colons. A bare `UTC` or
+/// `GMT` is left to the database.
+fn parse_offset(text: &str) -> Option<Offset> {
+    let rest = match text.get(..3) {
+        Some(prefix)
+            if prefix.eq_ignore_ascii_case("UTC") || prefix.eq_ignore_ascii_case("GMT") =>
+        {
+            text[3..].trim_start()
+        }
+        _ => text,
+    };
+    let (sign, rest) = if let Some(rest) = rest.strip_prefix('+') {
+        (1, rest)
+    } else {
+        (-1, rest.strip_prefix(['-', '\u{2212}'])?)
+    };
+
+    let digits: Vec<_> = if rest.contains(':') {
+        rest.split(':').map(|part| (part.len() <= 2).then_some(part)).collect::<Option<_>>()?
+    } else {
+        match rest.len() {
+            1 | 2 => Vec::from([rest]),
+            3 => Vec::from([&rest[..1], &rest[1..]]),
+            4 => Vec::from([&rest[..2], &rest[2..]]),
+            6 => Vec::from([&rest[..2], &rest[2..4], &rest[4..]]),
+            _ => return None,
+        }
+    };
+    let mut parts = digits.into_iter().map(|part| {
+        part.bytes().all(|byte| byte.is_ascii_digit()).then(|| part.parse::<i32>().ok()).flatten()
+    });
+    let hours = parts.next()??;
+    let minutes = parts.next().unwrap_or(Some(0))?;
+    let seconds = parts.next().unwrap_or(Some(0))?;
+    // No zone on Earth has ever been more than 14 hours away from UTC.
+    if parts.next().is_some() || hours > 14 || minutes >= 60 || seconds >= 60 {
+        return None;
+    }
+    Offset::from_seconds(sign * (hours * 3600 + minutes * 60 + seconds)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Unresolved, Zone};
+
+    /// Resolves the `text` with the abbreviations in use around 2025.
+    fn resolve(text: &str) -> Result<Zone, Unresolved> {
+        crate::util::abbr::init(2025);
+        super::resolve(text)
+    }
+
+    fn name(text: &str) -> String {
+        String::from(&*resolve(text).unwrap().name)
+    }
+
+    #[test]
+    fn resolves_every_form() {
+        assert_eq!(name(" Asia/Manila "), "Asia/Manila");
+        assert_eq!(name("asia/manila"), "Asia/Manila");
+        assert_eq!(name("+05:30"), "UTC+05:30");
+        assert_eq!(name("UTC-8"), "UTC-08:00");
+        assert_eq!(name("GMT+2"), "UTC+02:00");
+        assert_eq!(name("utc +0545"), "UTC+05:45");
+        assert_eq!(name("UTC"), "UTC");
+        assert_eq!(name("Etc/GMT+2"), "Etc/GMT+2");
+        assert_eq!(name("PDT"), "UTC-07:00");
+        assert_eq!(name("cest"), "UTC+02:00");
+        assert_eq!(name("EST5EDT,M3.2.0,M11.1.0"), "EST5EDT,M3.2.0,M11.1.0");
+        assert_eq!(name("<+0330>-3:30"), "<+0330>-3:30");
+
+        // The canonical names resolve to the same zones again.
+        for text in ["+05:30", "UTC-8", "PDT", "EST5EDT,M3.2.0,M11.1.0"] {
+            assert_eq!(name(&name(text)), name(text));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_and_ambiguous() {
+        assert_eq!(resolve("Mars/Olympus").unwrap_err(), Unresolved::Unknown);
+        assert_eq!(resolve("+25").unwrap_err(), Unresolved::Unknown);
+        assert_eq!(resolve("UTC+5:75").unwrap_err(), Unresolved::Unknown);
+        assert_eq!(resolve("").unwrap_err(), Unresolved::Unknown);
+
+        let Err(Unresolved::Ambiguous { abbreviation, candidates }) = resolve("ist") else {
+            panic!("IST should be ambiguous");
+        };
+        assert_eq!(&*abbreviation, "IST");
+        for zone in ["Asia/Kolkata (UTC+05:30)", "Asia/Jerusalem (UTC+02:00)"] {
+            assert!(candidates.iter().any(|candidate| **candidate == *zone), "{candidates:?}");
+        }
+    }
+}